@@ -1,6 +1,12 @@
-use std::{env, fs, path::Path, process::Command};
+use std::{
+    env, fs,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 use anyhow::{Result, anyhow};
+use sha2::{Digest, Sha256};
 use which::which;
 
 fn join(root: &str, next: &str) -> String {
@@ -58,67 +64,140 @@ fn get_binary_url() -> String {
     )
 }
 
-#[cfg(not(target_os = "windows"))]
-fn download_cef(outdir: &str) -> Result<()> {
-    exec(
-        &format!(
-            "curl \
-                -L \
-                --retry 10 \
-                --retry-delay 3 \
-                --retry-connrefused \
-                --retry-max-time 300 \
-                -o ./cef.tar.bz2 \"{}\"",
-            get_binary_url(),
-        ),
-        outdir,
-    )?;
+/// The expected SHA-256 of the CEF distribution's `.tar.bz2`. `WEW_CEF_SHA256`
+/// overrides it directly; otherwise it's fetched from the mirror's
+/// `<archive>.tar.bz2.sha256` sidecar file, which CEF's CDN publishes
+/// alongside every artifact (the same source `sys/build.rs`'s download path
+/// checks against). A hand-pinned table would silently go stale the moment
+/// `get_binary_name()`'s version changes, so fetch it instead of hard-coding
+/// digests here.
+fn expected_sha256() -> Result<String> {
+    if let Ok(sha256) = env::var("WEW_CEF_SHA256") {
+        return Ok(sha256.trim().to_lowercase());
+    }
 
-    exec("tar -xjf ./cef.tar.bz2 -C ./", outdir)?;
-    exec("rm -f ./cef.tar.bz2", outdir)?;
-    exec(&format!("mv ./{} ./cef", get_binary_name()), outdir)?;
-    exec(
-        "mv ./cef/Release/cef_sandbox.a ./cef/Release/libcef_sandbox.a",
-        outdir,
-    )?;
+    let url = format!("{}.sha256", get_binary_url());
+    let body = reqwest::blocking::get(&url)?.error_for_status()?.text()?;
+
+    Ok(body
+        .split_whitespace()
+        .next()
+        .unwrap_or(&body)
+        .trim()
+        .to_lowercase())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Directory CEF distributions are cached in, keyed by the full
+/// version+platform+arch binary name so upgrades never collide with a
+/// stale extraction. Reuses `wrap_wew`'s `CACHE_PATH` when set so local
+/// builds and packaging share one cache.
+fn cache_root() -> PathBuf {
+    env::var("CACHE_PATH")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| env::temp_dir().join("wew-cef-cache"))
+}
+
+/// Streams the CEF distribution to `dest` while hashing it, aborting
+/// before the partial file is trusted if the digest doesn't match
+/// `expected_sha256`.
+fn download_with_hash_check(url: &str, dest: &Path, expected: Option<&str>) -> Result<()> {
+    let mut response = reqwest::blocking::get(url)?.error_for_status()?;
+    let mut file = fs::File::create(dest)?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+
+    loop {
+        let read = response.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+
+        hasher.update(&buf[..read]);
+        file.write_all(&buf[..read])?;
+    }
+
+    drop(file);
+
+    let digest = to_hex(&hasher.finalize());
+    if let Some(expected) = expected {
+        if digest != expected {
+            fs::remove_file(dest).ok();
+            return Err(anyhow!(
+                "CEF distribution hash mismatch: expected {expected}, got {digest}"
+            ));
+        }
+    }
 
     Ok(())
 }
 
-#[cfg(target_os = "windows")]
-fn download_cef(outdir: &str) -> Result<()> {
-    if !fs::exists(&join(outdir, "./7za.exe")).unwrap_or(false) {
-        exec(
-            "Invoke-WebRequest -Uri 'https://7-zip.org/a/7za920.zip' -OutFile ./7za.zip",
-            outdir,
-        )?;
-
-        exec(
-            "Expand-Archive -Path ./7za.zip -DestinationPath ./7za",
-            outdir,
-        )?;
-
-        exec("Move-Item ./7za/7za.exe ./7za.exe", outdir)?;
-        exec("Remove-Item -Recurse -Force ./7za", outdir)?;
-        exec("Remove-Item ./7za.zip", outdir)?;
+fn copy_dir_all(src: &Path, dst: &Path) -> Result<()> {
+    fs::create_dir_all(dst)?;
+
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let dst_path = dst.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &dst_path)?;
+        } else {
+            fs::copy(entry.path(), dst_path)?;
+        }
     }
 
-    exec(
-        &format!(
-            "Invoke-WebRequest -Uri {} -OutFile ./cef.tar.bz2",
-            get_binary_url(),
-        ),
-        outdir,
-    )?;
+    Ok(())
+}
 
-    exec("./7za.exe x ./cef.tar.bz2", outdir)?;
-    exec("./7za.exe x ./cef.tar", outdir)?;
-    exec("Remove-Item ./cef.tar.bz2", outdir)?;
-    exec("Remove-Item ./cef.tar", outdir)?;
-    exec(
-        &format!("Rename-Item ./{} ./cef", get_binary_name()),
-        outdir,
-    )?;
+/// Fetches, verifies, and extracts the CEF distribution into `cef_dir`,
+/// reusing a version-stamped cache directory across builds/CI machines
+/// instead of re-downloading every time `OUT_DIR` is wiped.
+///
+/// - If the cache already holds an extracted copy for this exact CEF
+///   version/platform/arch, it's copied straight into place.
+/// - Otherwise the archive is streamed to a temp file while hashing, the
+///   digest is checked against `expected_sha256`, and only then is it
+///   decompressed/untarred into a temp directory and atomically renamed
+///   into the cache.
+fn download_cef(cef_dir: &str) -> Result<()> {
+    let binary_name = get_binary_name();
+    let cache_dir = cache_root();
+    fs::create_dir_all(&cache_dir)?;
+    let cached_extract = cache_dir.join(&binary_name);
+
+    if !cached_extract.exists() {
+        let archive_path = cache_dir.join(format!("{binary_name}.tar.bz2.part"));
+        let expected = expected_sha256()?;
+        download_with_hash_check(&get_binary_url(), &archive_path, Some(&expected))?;
+
+        let extract_tmp = cache_dir.join(format!("{binary_name}.tmp-{}", std::process::id()));
+        fs::create_dir_all(&extract_tmp)?;
+
+        let archive = fs::File::open(&archive_path)?;
+        tar::Archive::new(bzip2::read::BzDecoder::new(archive)).unpack(&extract_tmp)?;
+        fs::remove_file(&archive_path)?;
+
+        // The archive's sole top-level entry is the binary_name directory
+        // itself; renaming it is the atomic "publish" step into the cache.
+        fs::rename(extract_tmp.join(&binary_name), &cached_extract)?;
+        fs::remove_dir_all(&extract_tmp).ok();
+    }
+
+    if fs::exists(cef_dir).unwrap_or(false) {
+        fs::remove_dir_all(cef_dir)?;
+    }
+    copy_dir_all(&cached_extract, Path::new(cef_dir))?;
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let sandbox = Path::new(cef_dir).join("Release/cef_sandbox.a");
+        if sandbox.exists() {
+            fs::rename(&sandbox, Path::new(cef_dir).join("Release/libcef_sandbox.a"))?;
+        }
+    }
 
     Ok(())
 }
@@ -240,7 +319,7 @@ fn main() -> Result<()> {
     }
 
     if !fs::exists(cef_dir).unwrap_or(false) {
-        download_cef(&outdir)?;
+        download_cef(cef_dir)?;
     }
 
     if !fs::exists(&join(cef_dir, "./libcef_dll_wrapper")).unwrap_or(false) {
@@ -87,7 +87,12 @@ impl ApplicationHandler<UserEvent> for App {
             UserEvent::RuntimeContextInitialized => {
                 if let Some(window) = self.window.as_ref() {
                     // Create renderer
-                    let render = pollster::block_on(render::Render::new(window.clone())).unwrap();
+                    let render = pollster::block_on(render::Render::new(
+                        window.clone(),
+                        render::AlphaMode::default(),
+                        render::RenderOptions::default(),
+                    ))
+                    .unwrap();
 
                     // Get the current winit window's native window handle to pass to the webview
                     // for binding relationships with popup windows, etc.
@@ -9,7 +9,71 @@ use wgpu::{
     *,
 };
 
-use winit::window::Window;
+use winit::{dpi::PhysicalSize, raw_window_handle, window::Window};
+
+pub mod ffi;
+
+/// Whether the rect/popup overlay buffer CEF delivers carries premultiplied
+/// or straight alpha. CEF documents its shared-texture and software paint
+/// buffers as premultiplied, so that's the default; `Straight` is offered in
+/// case a particular backend turns out to emit non-premultiplied alpha.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlphaMode {
+    #[default]
+    Premultiplied,
+    Straight,
+}
+
+impl AlphaMode {
+    fn fragment_shader(self) -> &'static str {
+        match self {
+            Self::Premultiplied => FRAGMENT_SHADER_PREMULTIPLIED,
+            Self::Straight => FRAGMENT_SHADER_STRAIGHT,
+        }
+    }
+}
+
+/// Tunables for [`Render::new`] that used to be hard-coded per-OS policy:
+/// the present mode (latency vs. tearing vs. power) and the adapter power
+/// preference.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderOptions {
+    /// Requested present mode; falls back to `PresentMode::Fifo` if the
+    /// surface doesn't support it.
+    pub present_mode: PresentMode,
+    pub power_preference: PowerPreference,
+    /// Request an HDR/wide-gamut `Rgba16Float` surface and intermediate
+    /// textures instead of `Bgra8Unorm`, when the surface supports it.
+    /// Falls back to `Bgra8Unorm` otherwise.
+    pub hdr: bool,
+}
+
+impl Default for RenderOptions {
+    /// Matches this crate's previous hard-coded per-OS present mode:
+    /// `Mailbox` on Windows, `Fifo` on Linux, `Immediate` elsewhere.
+    fn default() -> Self {
+        Self {
+            present_mode: if cfg!(target_os = "windows") {
+                PresentMode::Mailbox
+            } else if cfg!(target_os = "linux") {
+                PresentMode::Fifo
+            } else {
+                PresentMode::Immediate
+            },
+            power_preference: PowerPreference::LowPower,
+            hdr: false,
+        }
+    }
+}
+
+/// Bytes per pixel for the texture formats [`Context::new`] can select
+/// between. Used to compute `bytes_per_row` when uploading frame data.
+fn bytes_per_pixel(format: TextureFormat) -> u32 {
+    match format {
+        TextureFormat::Rgba16Float => 8,
+        _ => 4,
+    }
+}
 
 static VERTEX_SHADER: &str = r#"
     struct VertexOutput {
@@ -25,16 +89,42 @@ static VERTEX_SHADER: &str = r#"
     }
 "#;
 
-static FRAGMENT_SHADER: &str = r#"
+/// Composites `rect_texture_` (the popup/select-dropdown overlay) over
+/// `view_texture_` with real source-over alpha blending, assuming CEF
+/// delivered `rect_texture_` with premultiplied alpha (its documented
+/// format): the RGB channels are already scaled by alpha, so the blend is a
+/// straight add.
+static FRAGMENT_SHADER_PREMULTIPLIED: &str = r#"
     @group(0) @binding(0) var view_texture_: texture_2d<f32>;
     @group(0) @binding(1) var rect_texture_: texture_2d<f32>;
     @group(0) @binding(2) var sampler_: sampler;
-    
+
     @fragment fn main(@location(0) coords: vec2<f32>) -> @location(0) vec4<f32> {
         let view = textureSample(view_texture_, sampler_, coords);
         let rect = textureSample(rect_texture_, sampler_, coords);
 
-        return select(view, rect, rect.a > 0.0);
+        let out_rgb = rect.rgb + view.rgb * (1.0 - rect.a);
+        let out_a = rect.a + view.a * (1.0 - rect.a);
+
+        return vec4<f32>(out_rgb, out_a);
+    }
+"#;
+
+/// Same as [`FRAGMENT_SHADER_PREMULTIPLIED`], but for a `rect_texture_` with
+/// straight (non-premultiplied) alpha.
+static FRAGMENT_SHADER_STRAIGHT: &str = r#"
+    @group(0) @binding(0) var view_texture_: texture_2d<f32>;
+    @group(0) @binding(1) var rect_texture_: texture_2d<f32>;
+    @group(0) @binding(2) var sampler_: sampler;
+
+    @fragment fn main(@location(0) coords: vec2<f32>) -> @location(0) vec4<f32> {
+        let view = textureSample(view_texture_, sampler_, coords);
+        let rect = textureSample(rect_texture_, sampler_, coords);
+
+        let out_rgb = mix(view.rgb, rect.rgb, rect.a);
+        let out_a = rect.a + view.a * (1.0 - rect.a);
+
+        return vec4<f32>(out_rgb, out_a);
     }
 "#;
 
@@ -93,17 +183,64 @@ struct Context {
     vertex_buffer: Buffer,
     index_buffer: Buffer,
     sampler: Sampler,
+    alpha_mode: AlphaMode,
+    /// The format shared by the surface and both intermediate textures:
+    /// `Rgba16Float` when `options.hdr` was requested and the surface
+    /// supports it, `Bgra8Unorm` otherwise.
+    format: TextureFormat,
 }
 
 impl Context {
-    async fn new(window: Arc<Window>) -> Result<Self> {
+    async fn new(window: Arc<Window>, alpha_mode: AlphaMode, options: RenderOptions) -> Result<Self> {
         let size = window.inner_size();
 
         let instance = Instance::new(&InstanceDescriptor::default());
         let surface = instance.create_surface(window)?;
+
+        Self::new_with_surface(instance, surface, size.width, size.height, alpha_mode, options)
+            .await
+    }
+
+    /// Build a [`Context`] from a raw platform window/display handle rather
+    /// than a `winit::Window`, so a non-Rust host can drive the compositor
+    /// without linking winit. See [`ffi`].
+    ///
+    /// # Safety
+    ///
+    /// `raw_display_handle` and `raw_window_handle` must be valid for as
+    /// long as the returned `Context` (and anything built from it) is alive.
+    async unsafe fn new_from_raw(
+        raw_display_handle: raw_window_handle::RawDisplayHandle,
+        raw_window_handle: raw_window_handle::RawWindowHandle,
+        width: u32,
+        height: u32,
+        alpha_mode: AlphaMode,
+        options: RenderOptions,
+    ) -> Result<Self> {
+        let instance = Instance::new(&InstanceDescriptor::default());
+        let surface = unsafe {
+            instance.create_surface_unsafe(SurfaceTargetUnsafe::RawHandle {
+                raw_display_handle,
+                raw_window_handle,
+            })
+        }?;
+
+        Self::new_with_surface(instance, surface, width, height, alpha_mode, options).await
+    }
+
+    async fn new_with_surface(
+        instance: Instance,
+        surface: Surface<'static>,
+        width: u32,
+        height: u32,
+        alpha_mode: AlphaMode,
+        options: RenderOptions,
+    ) -> Result<Self> {
+        let size = PhysicalSize::new(width, height);
+
         let adapter = instance
             .request_adapter(&RequestAdapterOptions {
-                power_preference: PowerPreference::LowPower,
+                power_preference: options.power_preference,
                 compatible_surface: Some(&surface),
                 force_fallback_adapter: false,
                 ..Default::default()
@@ -123,18 +260,42 @@ impl Context {
             .get_default_config(&adapter, size.width, size.height)
             .unwrap();
 
+        let present_mode = if surface
+            .get_capabilities(&adapter)
+            .present_modes
+            .contains(&options.present_mode)
+        {
+            options.present_mode
+        } else {
+            PresentMode::Fifo
+        };
+
+        let capabilities = surface.get_capabilities(&adapter);
+
+        let format = if options.hdr && capabilities.formats.contains(&TextureFormat::Rgba16Float) {
+            TextureFormat::Rgba16Float
+        } else {
+            TextureFormat::Bgra8Unorm
+        };
+
+        // `Rgba16Float` surfaces typically only composite correctly with
+        // `PostMultiplied`/`PreMultiplied` alpha, so prefer those over
+        // `Opaque` when we actually picked the HDR format.
+        let composite_alpha_mode = if format == TextureFormat::Rgba16Float {
+            [CompositeAlphaMode::PostMultiplied, CompositeAlphaMode::PreMultiplied]
+                .into_iter()
+                .find(|mode| capabilities.alpha_modes.contains(mode))
+                .unwrap_or(CompositeAlphaMode::Opaque)
+        } else {
+            CompositeAlphaMode::Opaque
+        };
+
         surface.configure(&device, {
-            surface_config.present_mode = if cfg!(target_os = "windows") {
-                PresentMode::Mailbox
-            } else if cfg!(target_os = "linux") {
-                PresentMode::Fifo
-            } else {
-                PresentMode::Immediate
-            };
+            surface_config.present_mode = present_mode;
 
-            surface_config.format = TextureFormat::Bgra8Unorm;
-            surface_config.alpha_mode = CompositeAlphaMode::Opaque;
-            surface_config.usage = TextureUsages::RENDER_ATTACHMENT;
+            surface_config.format = format;
+            surface_config.alpha_mode = composite_alpha_mode;
+            surface_config.usage = TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC;
 
             &surface_config
         });
@@ -172,6 +333,8 @@ impl Context {
             surface_config,
             vertex_buffer,
             index_buffer,
+            alpha_mode,
+            format,
         })
     }
 
@@ -183,6 +346,17 @@ impl Context {
     }
 }
 
+/// Dimensions and tightly-packed pixel bytes captured via [`Render::capture`].
+/// `data` has no row padding: `data.len() == width * height * bytes_per_pixel`.
+pub struct CapturedFrame {
+    pub width: u32,
+    pub height: u32,
+    /// Pixel bytes in the compositor's native format: BGRA8 (4 bytes/pixel,
+    /// `Bgra8Unorm`) normally, or RGBA16F (8 bytes/pixel, `Rgba16Float`)
+    /// when [`RenderOptions::hdr`] was requested and granted.
+    pub data: Vec<u8>,
+}
+
 pub struct Render {
     context: Context,
     view_texture: Texture,
@@ -191,31 +365,81 @@ pub struct Render {
     bind_group_layout: BindGroupLayout,
     bind_group: BindGroup,
     pipeline: RenderPipeline,
+    /// Set whenever `view_texture` is (re)created, i.e. on the first frame
+    /// and right after a resize. The texture's contents are undefined until
+    /// the next `View` frame, so that frame must do a full upload even if it
+    /// only reports a partial `dirty_rects` list.
+    view_texture_needs_full_upload: bool,
+    /// Offscreen copy of the composited frame, written to at the end of
+    /// every `render` call so [`Render::capture`] has something stable to
+    /// read back without holding the swapchain's `SurfaceTexture` open.
+    capture_texture: Texture,
 }
 
 impl Render {
-    pub async fn new(window: Arc<Window>) -> Result<Self> {
+    pub async fn new(
+        window: Arc<Window>,
+        alpha_mode: AlphaMode,
+        options: RenderOptions,
+    ) -> Result<Self> {
         let size = window.inner_size();
-        let context = Context::new(window).await?;
+        let context = Context::new(window, alpha_mode, options).await?;
+
+        Self::from_context(context, size.width, size.height)
+    }
+
+    /// Build a `Render` from a raw platform window/display handle rather
+    /// than a `winit::Window`, so a non-Rust host can drive the compositor
+    /// through [`ffi`] without linking winit.
+    ///
+    /// # Safety
+    ///
+    /// `raw_display_handle` and `raw_window_handle` must be valid for as
+    /// long as the returned `Render` is alive.
+    pub async unsafe fn new_from_raw(
+        raw_display_handle: raw_window_handle::RawDisplayHandle,
+        raw_window_handle: raw_window_handle::RawWindowHandle,
+        width: u32,
+        height: u32,
+        alpha_mode: AlphaMode,
+        options: RenderOptions,
+    ) -> Result<Self> {
+        let context = unsafe {
+            Context::new_from_raw(
+                raw_display_handle,
+                raw_window_handle,
+                width,
+                height,
+                alpha_mode,
+                options,
+            )
+        }
+        .await?;
+
+        Self::from_context(context, width, height)
+    }
+
+    fn from_context(context: Context, width: u32, height: u32) -> Result<Self> {
+        let size = PhysicalSize::new(width, height);
 
         let view_texture = context
             .device
-            .create_texture(&texture_descriptor(size.width, size.height));
+            .create_texture(&texture_descriptor(size.width, size.height, context.format));
 
         let view_texture_view = view_texture.create_view(&TextureViewDescriptor {
             dimension: Some(TextureViewDimension::D2),
-            format: Some(TextureFormat::Bgra8Unorm),
+            format: Some(context.format),
             aspect: TextureAspect::All,
             ..Default::default()
         });
 
         let rect_texture = context
             .device
-            .create_texture(&texture_descriptor(size.width, size.height));
+            .create_texture(&texture_descriptor(size.width, size.height, context.format));
 
         let rect_texture_view = rect_texture.create_view(&TextureViewDescriptor {
             dimension: Some(TextureViewDimension::D2),
-            format: Some(TextureFormat::Bgra8Unorm),
+            format: Some(context.format),
             aspect: TextureAspect::All,
             ..Default::default()
         });
@@ -299,13 +523,15 @@ impl Render {
                         entry_point: Some("main"),
                         module: &context.device.create_shader_module(ShaderModuleDescriptor {
                             label: None,
-                            source: ShaderSource::Wgsl(Cow::Borrowed(FRAGMENT_SHADER)),
+                            source: ShaderSource::Wgsl(Cow::Borrowed(
+                                context.alpha_mode.fragment_shader(),
+                            )),
                         }),
                         compilation_options: PipelineCompilationOptions::default(),
                         targets: &[Some(ColorTargetState {
                             blend: Some(BlendState::REPLACE),
                             write_mask: ColorWrites::ALL,
-                            format: TextureFormat::Bgra8Unorm,
+                            format: context.format,
                         })],
                     }),
                     primitive: PrimitiveState {
@@ -319,6 +545,21 @@ impl Render {
                     cache: None,
                 });
 
+        let capture_texture = context.device.create_texture(&TextureDescriptor {
+            label: None,
+            view_formats: &[],
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: context.format,
+            usage: TextureUsages::COPY_DST | TextureUsages::COPY_SRC,
+            size: Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+        });
+
         Ok(Self {
             context,
             pipeline,
@@ -327,6 +568,8 @@ impl Render {
             rect_texture_view,
             bind_group_layout,
             bind_group,
+            view_texture_needs_full_upload: true,
+            capture_texture,
         })
     }
 
@@ -338,22 +581,61 @@ impl Render {
             self.resize(frame.width, frame.height);
         }
 
+        let bytes_per_pixel = bytes_per_pixel(self.context.format);
+
         if frame.ty == FrameType::View {
-            self.context.queue.write_texture(
-                TexelCopyTextureInfo {
-                    texture: &self.view_texture,
-                    aspect: TextureAspect::All,
-                    origin: Origin3d::ZERO,
-                    mip_level: 0,
-                },
-                frame.buffer,
-                TexelCopyBufferLayout {
-                    bytes_per_row: Some(frame.width * 4),
-                    rows_per_image: Some(frame.height),
-                    offset: 0,
-                },
-                self.view_texture.size(),
-            );
+            let stride = frame.width * bytes_per_pixel;
+
+            if self.view_texture_needs_full_upload || frame.dirty_rects.is_empty() {
+                self.context.queue.write_texture(
+                    TexelCopyTextureInfo {
+                        texture: &self.view_texture,
+                        aspect: TextureAspect::All,
+                        origin: Origin3d::ZERO,
+                        mip_level: 0,
+                    },
+                    frame.buffer,
+                    TexelCopyBufferLayout {
+                        bytes_per_row: Some(stride),
+                        rows_per_image: Some(frame.height),
+                        offset: 0,
+                    },
+                    self.view_texture.size(),
+                );
+
+                self.view_texture_needs_full_upload = false;
+            } else {
+                for rect in frame.dirty_rects {
+                    if rect.width == 0 || rect.height == 0 {
+                        continue;
+                    }
+
+                    self.context.queue.write_texture(
+                        TexelCopyTextureInfo {
+                            texture: &self.view_texture,
+                            aspect: TextureAspect::All,
+                            mip_level: 0,
+                            origin: Origin3d {
+                                x: rect.x,
+                                y: rect.y,
+                                z: 0,
+                            },
+                        },
+                        frame.buffer,
+                        TexelCopyBufferLayout {
+                            bytes_per_row: Some(stride),
+                            rows_per_image: Some(frame.height),
+                            offset: (rect.y as u64 * stride as u64)
+                                + (rect.x as u64 * bytes_per_pixel as u64),
+                        },
+                        Extent3d {
+                            width: rect.width,
+                            height: rect.height,
+                            depth_or_array_layers: 1,
+                        },
+                    );
+                }
+            }
         } else {
             self.context.queue.write_texture(
                 TexelCopyTextureInfo {
@@ -368,7 +650,7 @@ impl Render {
                 },
                 frame.buffer,
                 TexelCopyBufferLayout {
-                    bytes_per_row: Some(frame.width * 4),
+                    bytes_per_row: Some(frame.width * bytes_per_pixel),
                     rows_per_image: Some(frame.height),
                     offset: 0,
                 },
@@ -412,6 +694,8 @@ impl Render {
             }
 
             {
+                // All-zero, so it's a valid "fully transparent" clear value
+                // under both premultiplied and straight alpha.
                 encoder.begin_render_pass(&RenderPassDescriptor {
                     color_attachments: &[Some(RenderPassColorAttachment {
                         view: &self.rect_texture_view,
@@ -425,34 +709,126 @@ impl Render {
                 });
             }
 
+            encoder.copy_texture_to_texture(
+                TexelCopyTextureInfo {
+                    texture: &output.texture,
+                    aspect: TextureAspect::All,
+                    origin: Origin3d::ZERO,
+                    mip_level: 0,
+                },
+                TexelCopyTextureInfo {
+                    texture: &self.capture_texture,
+                    aspect: TextureAspect::All,
+                    origin: Origin3d::ZERO,
+                    mip_level: 0,
+                },
+                self.capture_texture.size(),
+            );
+
             self.context.queue.submit(Some(encoder.finish()));
             output.present();
         }
     }
 
-    fn resize(&mut self, width: u32, height: u32) {
-        self.context.resize(width, height);
+    /// Read the most recently composited frame back from the GPU.
+    ///
+    /// Blocks the calling thread while the copy and buffer map complete.
+    /// Before the first call to [`Render::render`] this returns a
+    /// zero-filled frame at the surface's initial size.
+    pub fn capture(&mut self) -> Result<CapturedFrame> {
+        let width = self.capture_texture.width();
+        let height = self.capture_texture.height();
+        let bytes_per_pixel = bytes_per_pixel(self.context.format);
+
+        // `bytes_per_row` for a buffer copy destination must be a multiple of
+        // 256, so pad each row and strip the padding back out below.
+        let unpadded_bytes_per_row = width * bytes_per_pixel;
+        let padded_bytes_per_row =
+            unpadded_bytes_per_row.div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT) * COPY_BYTES_PER_ROW_ALIGNMENT;
+
+        let buffer = self.context.device.create_buffer(&BufferDescriptor {
+            label: None,
+            size: (padded_bytes_per_row * height) as BufferAddress,
+            usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
 
-        let view_texture = self
+        let mut encoder = self
             .context
             .device
-            .create_texture(&texture_descriptor(width, height));
+            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+
+        encoder.copy_texture_to_buffer(
+            TexelCopyTextureInfo {
+                texture: &self.capture_texture,
+                aspect: TextureAspect::All,
+                origin: Origin3d::ZERO,
+                mip_level: 0,
+            },
+            TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            self.capture_texture.size(),
+        );
+
+        self.context.queue.submit(Some(encoder.finish()));
+
+        let slice = buffer.slice(..);
+        let (tx, rx) = std::sync::mpsc::channel();
+        slice.map_async(MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+
+        self.context.device.poll(PollType::Wait)?;
+        rx.recv()??;
+
+        let padded = slice.get_mapped_range();
+        let mut data = Vec::with_capacity((unpadded_bytes_per_row * height) as usize);
+
+        for row in padded.chunks(padded_bytes_per_row as usize) {
+            data.extend_from_slice(&row[..unpadded_bytes_per_row as usize]);
+        }
+
+        drop(padded);
+        buffer.unmap();
+
+        Ok(CapturedFrame {
+            width,
+            height,
+            data,
+        })
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        self.context.resize(width, height);
+
+        let view_texture = self.context.device.create_texture(&texture_descriptor(
+            width,
+            height,
+            self.context.format,
+        ));
 
         let view_texture_view = view_texture.create_view(&TextureViewDescriptor {
             dimension: Some(TextureViewDimension::D2),
-            format: Some(TextureFormat::Bgra8Unorm),
+            format: Some(self.context.format),
             aspect: TextureAspect::All,
             ..Default::default()
         });
 
-        let rect_texture = self
-            .context
-            .device
-            .create_texture(&texture_descriptor(width, height));
+        let rect_texture = self.context.device.create_texture(&texture_descriptor(
+            width,
+            height,
+            self.context.format,
+        ));
 
         self.rect_texture_view = rect_texture.create_view(&TextureViewDescriptor {
             dimension: Some(TextureViewDimension::D2),
-            format: Some(TextureFormat::Bgra8Unorm),
+            format: Some(self.context.format),
             aspect: TextureAspect::All,
             ..Default::default()
         });
@@ -475,17 +851,40 @@ impl Render {
                 },
             ],
         });
+
+        self.view_texture = view_texture;
+        self.rect_texture = rect_texture;
+
+        self.capture_texture = self.context.device.create_texture(&TextureDescriptor {
+            label: None,
+            view_formats: &[],
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: self.context.format,
+            usage: TextureUsages::COPY_DST | TextureUsages::COPY_SRC,
+            size: Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        });
+
+        // The new view_texture's contents are undefined until the next
+        // `View` frame, so force a full upload even if that frame reports a
+        // partial dirty_rects list.
+        self.view_texture_needs_full_upload = true;
     }
 }
 
-fn texture_descriptor(width: u32, height: u32) -> TextureDescriptor<'static> {
+fn texture_descriptor(width: u32, height: u32, format: TextureFormat) -> TextureDescriptor<'static> {
     TextureDescriptor {
         label: None,
         view_formats: &[],
         mip_level_count: 1,
         sample_count: 1,
         dimension: TextureDimension::D2,
-        format: TextureFormat::Bgra8Unorm,
+        format,
         usage: TextureUsages::RENDER_ATTACHMENT
             | TextureUsages::TEXTURE_BINDING
             | TextureUsages::COPY_DST,
@@ -0,0 +1,187 @@
+//! A stable `extern "C"` surface over [`super::Render`], so a C/C++ or other
+//! FFI host can embed this example's compositor without linking Rust
+//! directly, following the C-API pattern used by other cxxbridge-style CEF
+//! renderers. Building this module into a C-consumable artifact requires
+//! configuring this crate with `crate-type = ["cdylib"]`; as an example
+//! binary it's included here to document the intended bridge shape.
+//!
+//! ## Thread affinity
+//!
+//! Every function here must be called from the same thread that called
+//! [`wew_render_new`]. `Render` wraps a `wgpu::Surface` tied to a native
+//! window, and neither wgpu nor the underlying platform surface is
+//! guaranteed to tolerate cross-thread use. Treat a `*mut Render` handle the
+//! same way you'd treat a native window handle: confined to one thread
+//! (typically the host's UI thread) for its entire lifetime.
+
+use std::ffi::c_void;
+
+use wew::webview::FrameType;
+use winit::raw_window_handle;
+
+use super::{AlphaMode, Render, RenderOptions};
+
+/// Build a `Render` from a raw platform window handle.
+///
+/// `window_handle` is an `HWND` on Windows or an `NSView*` on macOS, matching
+/// the subset of platforms this example's `main.rs` already supports.
+/// Returns null on failure (unsupported platform, adapter/device creation
+/// failure, etc).
+///
+/// # Safety
+///
+/// `window_handle` must be a valid, live platform window handle for the
+/// entire lifetime of the returned `Render`. The returned pointer must only
+/// ever be passed to the other `wew_render_*` functions in this module, and
+/// only from the thread that called this function.
+#[no_mangle]
+pub unsafe extern "C" fn wew_render_new(
+    window_handle: *mut c_void,
+    width: u32,
+    height: u32,
+) -> *mut Render {
+    let Some((raw_display_handle, raw_window_handle)) = (unsafe { raw_handles(window_handle) })
+    else {
+        return std::ptr::null_mut();
+    };
+
+    let render = pollster::block_on(unsafe {
+        Render::new_from_raw(
+            raw_display_handle,
+            raw_window_handle,
+            width,
+            height,
+            AlphaMode::default(),
+            RenderOptions::default(),
+        )
+    });
+
+    match render {
+        Ok(render) => Box::into_raw(Box::new(render)),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Upload one frame's pixel data and composite it. Mirrors
+/// [`super::Render::render`]; `ty` is `0` for a view frame, `1` for a popup
+/// frame. `buffer`/`len` must describe a tightly-packed `w * h *
+/// bytes-per-pixel` BGRA (or, under HDR, RGBA16F) buffer with an upper-left
+/// origin, matching `wew::webview::Frame::buffer`.
+///
+/// # Safety
+///
+/// `handle` must be a live pointer returned by [`wew_render_new`] and not
+/// yet passed to [`wew_render_free`]. `buffer` must be valid for `len` bytes
+/// for the duration of this call.
+#[no_mangle]
+pub unsafe extern "C" fn wew_render_submit_frame(
+    handle: *mut Render,
+    ty: u32,
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+    buffer: *const u8,
+    len: usize,
+) {
+    if handle.is_null() || buffer.is_null() {
+        return;
+    }
+
+    let render = unsafe { &mut *handle };
+    let buffer = unsafe { std::slice::from_raw_parts(buffer, len) };
+
+    let frame = wew::webview::Frame {
+        ty: if ty == 0 {
+            FrameType::View
+        } else {
+            FrameType::Popup
+        },
+        buffer,
+        x,
+        y,
+        width: w,
+        height: h,
+        dirty_rects: &[],
+    };
+
+    render.render(&frame);
+}
+
+/// Resize the compositor's surface and intermediate textures.
+///
+/// # Safety
+///
+/// `handle` must be a live pointer returned by [`wew_render_new`] and not
+/// yet passed to [`wew_render_free`].
+#[no_mangle]
+pub unsafe extern "C" fn wew_render_resize(handle: *mut Render, width: u32, height: u32) {
+    if handle.is_null() {
+        return;
+    }
+
+    (unsafe { &mut *handle }).resize(width, height);
+}
+
+/// Destroy a `Render` created by [`wew_render_new`].
+///
+/// # Safety
+///
+/// `handle` must either be null or a pointer returned by [`wew_render_new`]
+/// that hasn't already been freed. `handle` must not be used again after
+/// this call.
+#[no_mangle]
+pub unsafe extern "C" fn wew_render_free(handle: *mut Render) {
+    if !handle.is_null() {
+        drop(unsafe { Box::from_raw(handle) });
+    }
+}
+
+/// Build the `(RawDisplayHandle, RawWindowHandle)` pair for `window_handle`
+/// on the platforms this example supports.
+///
+/// # Safety
+///
+/// `window_handle` must be a valid, live platform window handle.
+unsafe fn raw_handles(
+    window_handle: *mut c_void,
+) -> Option<(
+    raw_window_handle::RawDisplayHandle,
+    raw_window_handle::RawWindowHandle,
+)> {
+    if window_handle.is_null() {
+        return None;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let window = raw_window_handle::Win32WindowHandle::new(
+            std::num::NonZeroIsize::new(window_handle as isize)?,
+        );
+
+        Some((
+            raw_window_handle::RawDisplayHandle::Windows(
+                raw_window_handle::WindowsDisplayHandle::new(),
+            ),
+            raw_window_handle::RawWindowHandle::Win32(window),
+        ))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let window =
+            raw_window_handle::AppKitWindowHandle::new(std::ptr::NonNull::new(window_handle)?);
+
+        Some((
+            raw_window_handle::RawDisplayHandle::AppKit(
+                raw_window_handle::AppKitDisplayHandle::new(),
+            ),
+            raw_window_handle::RawWindowHandle::AppKit(window),
+        ))
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    {
+        None
+    }
+}
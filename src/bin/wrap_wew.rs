@@ -10,13 +10,39 @@ use std::time::SystemTime;
 use tempfile::TempDir;
 use walkdir::WalkDir;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum PackageFormat {
+    /// Raw tarball of the assembled package directory (current behavior).
+    Tar,
+    /// macOS disk image, optionally codesigned.
+    Dmg,
+    /// Linux AppImage with bundled shared libraries.
+    AppImage,
+    /// Debian package whose `/usr/bin` wrapper script sets
+    /// `LD_LIBRARY_PATH`, so no manual `LD_LIBRARY_PATH` is required.
+    Deb,
+    /// Windows MSI installer built with the WiX toolset.
+    Msi,
+}
+
 #[derive(Parser)]
 #[command(name = "wrap_wew")]
 #[command(about = "A CLI tool to build and package wew applications")]
 struct Cli {
     #[arg(long, value_name = "PATH")]
     entrypoint: PathBuf,
-    
+
+    /// Output bundle type. Defaults to a raw tarball; platform-specific
+    /// formats require running on a matching host (Dmg/macOS, AppImage and
+    /// Deb/Linux, Msi/Windows).
+    #[arg(long, value_enum, default_value = "tar")]
+    format: PackageFormat,
+
+    /// Codesigning identity passed to `codesign --sign` when building a
+    /// `.dmg`. Without it the `.app`/helpers/framework are left unsigned.
+    #[arg(long, value_name = "IDENTITY")]
+    signing_identity: Option<String>,
+
     #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
     cargo_args: Vec<String>,
 }
@@ -485,6 +511,233 @@ fn create_tar_archive(source_dir: &Path, output_path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Recursively codesigns everything under `app_bundle` (helpers and the
+/// framework first, then the outer `.app`) with `identity`, matching the
+/// order Apple's own `codesign` expects for nested bundles.
+fn codesign_macos_bundle(app_bundle: &Path, identity: &str) -> Result<()> {
+    for entry in WalkDir::new(app_bundle).contents_first(true) {
+        let entry = entry?;
+        let path = entry.path();
+        let is_nested_bundle = path
+            .extension()
+            .is_some_and(|ext| ext == "app" || ext == "framework");
+
+        if is_nested_bundle {
+            exec_command(
+                &format!(
+                    "codesign --force --deep --options runtime --sign \"{}\" \"{}\"",
+                    identity,
+                    path.display()
+                ),
+                app_bundle,
+                None,
+            )?;
+        }
+    }
+
+    exec_command(
+        &format!(
+            "codesign --force --deep --options runtime --sign \"{}\" \"{}\"",
+            identity,
+            app_bundle.display()
+        ),
+        app_bundle,
+        None,
+    )?;
+
+    Ok(())
+}
+
+/// Wraps a macOS `.app` bundle in a `.dmg`, codesigning it first when a
+/// signing identity was supplied.
+fn create_dmg(
+    app_bundle: &Path,
+    package_name: &str,
+    temp_dir: &Path,
+    signing_identity: Option<&str>,
+) -> Result<PathBuf> {
+    if let Some(identity) = signing_identity {
+        codesign_macos_bundle(app_bundle, identity)?;
+    }
+
+    let dmg_path = temp_dir.join(format!("{}.dmg", package_name));
+    exec_command(
+        &format!(
+            "hdiutil create -volname \"{}\" -srcfolder \"{}\" -ov -format UDZO \"{}\"",
+            package_name,
+            app_bundle.display(),
+            dmg_path.display()
+        ),
+        temp_dir,
+        None,
+    )?;
+
+    Ok(dmg_path)
+}
+
+/// Builds a Linux AppImage from `package_dir`, placing the package's own
+/// shared libraries in `usr/lib` and wiring `AppRun`'s `LD_LIBRARY_PATH`
+/// so end users never have to set it themselves.
+fn create_app_image(package_dir: &Path, package_name: &str, temp_dir: &Path) -> Result<PathBuf> {
+    let app_dir = temp_dir.join(format!("{}.AppDir", package_name));
+    let usr_bin = app_dir.join("usr/bin");
+    let usr_lib = app_dir.join("usr/lib");
+    fs::create_dir_all(&usr_bin)?;
+    fs::create_dir_all(&usr_lib)?;
+
+    for entry in fs::read_dir(package_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let is_shared_lib = path
+            .extension()
+            .is_some_and(|ext| ext == "so" || ext.to_string_lossy().starts_with("so"));
+
+        if path.is_file() && is_shared_lib {
+            fs::copy(&path, usr_lib.join(entry.file_name()))?;
+        } else if path.is_file() {
+            fs::copy(&path, usr_bin.join(entry.file_name()))?;
+        } else {
+            copy_dir_all(&path, &usr_lib.join(entry.file_name()))?;
+        }
+    }
+
+    let app_run = format!(
+        "#!/bin/sh\nHERE=\"$(dirname \"$(readlink -f \"${{0}}\")\")\"\nexport LD_LIBRARY_PATH=\"${{HERE}}/usr/lib:${{LD_LIBRARY_PATH}}\"\nexec \"${{HERE}}/usr/bin/{}\" \"$@\"\n",
+        package_name
+    );
+    let app_run_path = app_dir.join("AppRun");
+    fs::write(&app_run_path, app_run)?;
+    exec_command(&format!("chmod +x \"{}\"", app_run_path.display()), temp_dir, None)?;
+
+    let desktop_file = format!(
+        "[Desktop Entry]\nType=Application\nName={}\nExec={}\nIcon={}\nCategories=Utility;\n",
+        package_name, package_name, package_name
+    );
+    fs::write(app_dir.join(format!("{}.desktop", package_name)), desktop_file)?;
+
+    let appimage_path = temp_dir.join(format!("{}.AppImage", package_name));
+    exec_command(
+        &format!(
+            "appimagetool \"{}\" \"{}\"",
+            app_dir.display(),
+            appimage_path.display()
+        ),
+        temp_dir,
+        None,
+    )?;
+
+    Ok(appimage_path)
+}
+
+/// Builds a `.deb` from `package_dir`, installing its whole contents
+/// (binaries and shared libraries) under `/usr/lib/<package_name>` and a
+/// `/usr/bin/<package_name>` wrapper script that sets `LD_LIBRARY_PATH`, so
+/// users don't need to set the environment variable themselves.
+fn create_deb(package_dir: &Path, package_name: &str, temp_dir: &Path) -> Result<PathBuf> {
+    let deb_root = temp_dir.join(format!("{}-deb", package_name));
+    let lib_dir = deb_root.join("usr/lib").join(package_name);
+    let bin_dir = deb_root.join("usr/bin");
+    let debian_dir = deb_root.join("DEBIAN");
+    fs::create_dir_all(&lib_dir)?;
+    fs::create_dir_all(&bin_dir)?;
+    fs::create_dir_all(&debian_dir)?;
+
+    for entry in fs::read_dir(package_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_file() {
+            fs::copy(&path, lib_dir.join(entry.file_name()))?;
+        } else {
+            copy_dir_all(&path, &lib_dir.join(entry.file_name()))?;
+        }
+    }
+
+    let wrapper = format!(
+        "#!/bin/sh\nexport LD_LIBRARY_PATH=\"/usr/lib/{}:${{LD_LIBRARY_PATH}}\"\nexec \"/usr/lib/{}/{}\" \"$@\"\n",
+        package_name, package_name, package_name
+    );
+    let wrapper_path = bin_dir.join(package_name);
+    fs::write(&wrapper_path, wrapper)?;
+    exec_command(&format!("chmod +x \"{}\"", wrapper_path.display()), temp_dir, None)?;
+
+    let control = format!(
+        "Package: {}\nVersion: 1.0\nArchitecture: amd64\nMaintainer: unknown\nDescription: {}\n",
+        package_name, package_name
+    );
+    fs::write(debian_dir.join("control"), control)?;
+
+    let deb_path = temp_dir.join(format!("{}.deb", package_name));
+    exec_command(
+        &format!("dpkg-deb --build --root-owner-group \"{}\" \"{}\"", deb_root.display(), deb_path.display()),
+        temp_dir,
+        None,
+    )?;
+
+    Ok(deb_path)
+}
+
+/// Builds a Windows MSI from `package_dir` via the WiX toolset
+/// (`candle`/`light`), harvesting the package directory with `heat` so
+/// every DLL/resource already assembled there ends up in the installer.
+fn create_msi(package_dir: &Path, package_name: &str, temp_dir: &Path) -> Result<PathBuf> {
+    let harvest_wxs = temp_dir.join("harvest.wxs");
+    exec_command(
+        &format!(
+            "heat dir \"{}\" -cg PackageFiles -gg -scom -sreg -sfrag -srd -dr INSTALLFOLDER -var var.SourceDir -out \"{}\"",
+            package_dir.display(),
+            harvest_wxs.display()
+        ),
+        temp_dir,
+        None,
+    )?;
+
+    let product_wxs = temp_dir.join("product.wxs");
+    fs::write(
+        &product_wxs,
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<Wix xmlns="http://schemas.microsoft.com/wix/2006/wi">
+  <Product Id="*" Name="{0}" Language="1033" Version="1.0.0.0" Manufacturer="{0}" UpgradeCode="12345678-1234-1234-1234-123456789abc">
+    <Package InstallerVersion="200" Compressed="yes" InstallScope="perMachine" />
+    <MediaTemplate EmbedCab="yes" />
+    <Directory Id="TARGETDIR" Name="SourceDir">
+      <Directory Id="ProgramFilesFolder">
+        <Directory Id="INSTALLFOLDER" Name="{0}" />
+      </Directory>
+    </Directory>
+    <Feature Id="MainFeature" Title="{0}" Level="1">
+      <ComponentGroupRef Id="PackageFiles" />
+    </Feature>
+  </Product>
+</Wix>"#,
+            package_name
+        ),
+    )?;
+
+    exec_command(
+        &format!(
+            "candle -dSourceDir=\"{}\" \"{}\" \"{}\"",
+            package_dir.display(),
+            harvest_wxs.display(),
+            product_wxs.display()
+        ),
+        temp_dir,
+        None,
+    )?;
+
+    let msi_path = temp_dir.join(format!("{}.msi", package_name));
+    exec_command(
+        &format!(
+            "light -ext WixUIExtension harvest.wixobj product.wixobj -out \"{}\"",
+            msi_path.display()
+        ),
+        temp_dir,
+        None,
+    )?;
+
+    Ok(msi_path)
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
@@ -503,6 +756,7 @@ fn main() -> Result<()> {
     println!("Creating package for {}", package_name);
 
     // Create platform-specific package
+    let mut macos_app_bundle = None;
     let package_dir = if cfg!(target_os = "windows") {
         create_windows_package(&target_dir, &package_name, temp_path, &cargo_toml)?
     } else if cfg!(target_os = "macos") {
@@ -510,26 +764,75 @@ fn main() -> Result<()> {
         let app_bundle = create_macos_package(&target_dir, &package_name, temp_path, &cargo_toml)?;
         let package_wrapper = temp_path.join(&package_name);
         fs::create_dir_all(&package_wrapper)?;
-        
+
         // Move the .app bundle into the wrapper directory
         let app_name = format!("{}.app", package_name);
         let final_app_path = package_wrapper.join(&app_name);
         fs::rename(&app_bundle, &final_app_path)?;
-        
+        macos_app_bundle = Some(final_app_path);
+
         package_wrapper
     } else {
         create_linux_package(&target_dir, &package_name, temp_path, &cargo_toml)?
     };
 
-    // Create tar archive in current working directory
     let current_dir = env::current_dir()?;
-    let tar_name = format!("{}.tar", package_name);
-    let tar_path = current_dir.join(&tar_name);
 
-    println!("Creating archive: {}", tar_path.display());
-    create_tar_archive(&package_dir, &tar_path)?;
+    let output_path = match cli.format {
+        PackageFormat::Tar => {
+            let tar_path = current_dir.join(format!("{}.tar", package_name));
+            println!("Creating archive: {}", tar_path.display());
+            create_tar_archive(&package_dir, &tar_path)?;
+            tar_path
+        }
+        PackageFormat::Dmg => {
+            let app_bundle = macos_app_bundle
+                .as_deref()
+                .ok_or_else(|| anyhow!("--format dmg requires building on macOS"))?;
+            println!("Creating disk image for {}", package_name);
+            let dmg_path = create_dmg(
+                app_bundle,
+                &package_name,
+                temp_path,
+                cli.signing_identity.as_deref(),
+            )?;
+            let final_path = current_dir.join(format!("{}.dmg", package_name));
+            fs::copy(&dmg_path, &final_path)?;
+            final_path
+        }
+        PackageFormat::AppImage => {
+            if !cfg!(target_os = "linux") {
+                return Err(anyhow!("--format app-image requires building on Linux"));
+            }
+            println!("Creating AppImage for {}", package_name);
+            let appimage_path = create_app_image(&package_dir, &package_name, temp_path)?;
+            let final_path = current_dir.join(format!("{}.AppImage", package_name));
+            fs::copy(&appimage_path, &final_path)?;
+            final_path
+        }
+        PackageFormat::Deb => {
+            if !cfg!(target_os = "linux") {
+                return Err(anyhow!("--format deb requires building on Linux"));
+            }
+            println!("Creating .deb for {}", package_name);
+            let deb_path = create_deb(&package_dir, &package_name, temp_path)?;
+            let final_path = current_dir.join(format!("{}.deb", package_name));
+            fs::copy(&deb_path, &final_path)?;
+            final_path
+        }
+        PackageFormat::Msi => {
+            if !cfg!(target_os = "windows") {
+                return Err(anyhow!("--format msi requires building on Windows"));
+            }
+            println!("Creating MSI for {}", package_name);
+            let msi_path = create_msi(&package_dir, &package_name, temp_path)?;
+            let final_path = current_dir.join(format!("{}.msi", package_name));
+            fs::copy(&msi_path, &final_path)?;
+            final_path
+        }
+    };
+
+    println!("Successfully created {}", output_path.display());
 
-    println!("Successfully created {}", tar_name);
-    
     Ok(())
-} 
\ No newline at end of file
+}
\ No newline at end of file
@@ -29,6 +29,7 @@
 //!     expires: None,
 //!     same_site: SameSite::Lax,
 //!     priority: Priority::Medium,
+//!     partition_key: None,
 //! };
 //!
 //! manager.set_cookie("https://example.com", &cookie).unwrap();
@@ -44,12 +45,20 @@
 //! ```
 
 use std::{
+    collections::HashSet,
     ffi::{CString, c_void, c_char, c_int},
+    io::{self, BufRead, BufReader, Write},
     ptr::null_mut,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
     time::{SystemTime, UNIX_EPOCH},
 };
 
+use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine};
+use rand::RngCore;
+
 use crate::{
     sys,
     utils::ThreadSafePointer,
@@ -57,6 +66,7 @@ use crate::{
 
 /// Cookie same-site attribute values
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SameSite {
     /// No SameSite attribute specified
     Unspecified = 0,
@@ -68,8 +78,12 @@ pub enum SameSite {
     Strict = 3,
 }
 
-/// Cookie priority values
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Cookie priority values.
+///
+/// Ordered low-to-high so `Priority::Low < Priority::High`, matching the
+/// order [`CookieManager::garbage_collect`] evicts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Priority {
     /// Low priority
     Low = 0,
@@ -81,6 +95,7 @@ pub enum Priority {
 
 /// Represents an HTTP cookie
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cookie {
     /// The cookie name
     pub name: String,
@@ -100,6 +115,9 @@ pub struct Cookie {
     pub same_site: SameSite,
     /// Cookie priority
     pub priority: Priority,
+    /// The top-level site this cookie is partitioned under (CHIPS), e.g.
+    /// `https://example.com`. `None` means the cookie is unpartitioned.
+    pub partition_key: Option<String>,
 }
 
 impl Default for Cookie {
@@ -114,6 +132,7 @@ impl Default for Cookie {
             expires: None,
             same_site: SameSite::Unspecified,
             priority: Priority::Medium,
+            partition_key: None,
         }
     }
 }
@@ -179,6 +198,286 @@ impl Cookie {
         self.priority = priority;
         self
     }
+
+    /// Partition this cookie under `top_level_site` (CHIPS), e.g.
+    /// `https://example.com`.
+    pub fn partitioned(mut self, top_level_site: impl Into<String>) -> Self {
+        self.partition_key = Some(top_level_site.into());
+        self
+    }
+
+    /// Validate this cookie against `url` using the default 4096-byte
+    /// name+value cap. See [`Cookie::validate_for_with_limit`].
+    pub fn validate_for(&self, url: &str) -> Result<(), CookieError> {
+        self.validate_for_with_limit(url, DEFAULT_MAX_COOKIE_SIZE)
+    }
+
+    /// Check the `__Secure-`/`__Host-` name-prefix requirements, that the
+    /// domain is a valid suffix match of `url`'s host, and that
+    /// `name.len() + value.len()` is within `max_size`, modeled on
+    /// Chromium's `CanonicalCookie::Create`.
+    pub fn validate_for_with_limit(&self, url: &str, max_size: usize) -> Result<(), CookieError> {
+        if self.name.starts_with("__Secure-") && !self.secure {
+            return Err(CookieError::PrefixViolation);
+        }
+
+        if self.name.starts_with("__Host-")
+            && (!self.secure || self.path.as_deref() != Some("/") || self.domain.is_some())
+        {
+            return Err(CookieError::PrefixViolation);
+        }
+
+        if let Some(domain) = self.domain.as_deref() {
+            let host = host_from_url(url).ok_or(CookieError::InvalidUrl)?;
+            let domain = domain.trim_start_matches('.');
+
+            let matches = host.eq_ignore_ascii_case(domain)
+                || host
+                    .to_ascii_lowercase()
+                    .ends_with(&format!(".{}", domain.to_ascii_lowercase()));
+
+            if !matches {
+                return Err(CookieError::DomainMismatch);
+            }
+        }
+
+        if self.name.len() + self.value.len() > max_size {
+            return Err(CookieError::TooLarge);
+        }
+
+        Ok(())
+    }
+
+    /// Apply Chromium's `CanonicalCookie::Create` normalization rules
+    /// against `url`, returning a cookie safe to hand to [`CookieManager::set_cookie`]
+    /// or an error describing the first rule violated.
+    ///
+    /// - The name must not contain control characters or any of the RFC 2616
+    ///   separator tokens (`( ) < > @ , ; : \ " / [ ] ? = { }`) or whitespace.
+    /// - The value has surrounding quotes stripped and must not contain
+    ///   control characters.
+    /// - The domain is lowercased, a run of leading dots is folded to a
+    ///   single leading dot, and each non-ASCII label is punycode-encoded.
+    /// - An empty path defaults to the directory (everything up to the last
+    ///   `/`) of `url`'s path.
+    pub fn canonicalize(&self, url: &str) -> Result<Cookie, CookieError> {
+        if !is_valid_cookie_name(&self.name) {
+            return Err(CookieError::InvalidCookieName);
+        }
+
+        let value = canonicalize_cookie_value(&self.value)?;
+
+        let domain = self
+            .domain
+            .as_deref()
+            .map(canonicalize_cookie_domain)
+            .transpose()?;
+
+        let path = match self.path.as_deref() {
+            Some(path) if !path.is_empty() => path.to_string(),
+            _ => default_path_from_url(url),
+        };
+
+        Ok(Cookie {
+            name: self.name.clone(),
+            value,
+            domain,
+            path: Some(path),
+            ..self.clone()
+        })
+    }
+}
+
+/// RFC 2616 separator tokens that may not appear in a cookie name.
+const COOKIE_NAME_SEPARATORS: &[char] = &[
+    '(', ')', '<', '>', '@', ',', ';', ':', '\\', '"', '/', '[', ']', '?', '=', '{', '}',
+];
+
+fn is_valid_cookie_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| !c.is_control() && !c.is_whitespace() && !COOKIE_NAME_SEPARATORS.contains(&c))
+}
+
+/// Strip a single pair of surrounding double quotes, then reject any
+/// remaining control characters.
+fn canonicalize_cookie_value(value: &str) -> Result<String, CookieError> {
+    let value = value
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .unwrap_or(value);
+
+    if value.chars().any(|c| c.is_control()) {
+        return Err(CookieError::InvalidCookieValue);
+    }
+
+    Ok(value.to_string())
+}
+
+/// Lowercase the domain, fold a run of leading dots into a single leading
+/// dot (the canonical form CEF uses to mean "this domain and its
+/// subdomains"), and punycode-encode any label containing non-ASCII
+/// characters.
+fn canonicalize_cookie_domain(domain: &str) -> Result<String, CookieError> {
+    let had_leading_dot = domain.starts_with('.');
+    let domain = domain.trim_start_matches('.');
+
+    if domain.is_empty() {
+        return Err(CookieError::InvalidDomain);
+    }
+
+    let encoded = domain
+        .split('.')
+        .map(|label| {
+            if label.is_ascii() {
+                Ok(label.to_ascii_lowercase())
+            } else {
+                punycode_encode_label(&label.to_lowercase()).ok_or(CookieError::InvalidDomain)
+            }
+        })
+        .collect::<Result<Vec<_>, CookieError>>()?
+        .join(".");
+
+    Ok(if had_leading_dot {
+        format!(".{encoded}")
+    } else {
+        encoded
+    })
+}
+
+/// Encode a single non-ASCII domain label as an ACE (`xn--...`) label per
+/// RFC 3492. Labels that are already ASCII are returned unchanged by the
+/// caller and never reach this function.
+fn punycode_encode_label(label: &str) -> Option<String> {
+    const BASE: u32 = 36;
+    const TMIN: u32 = 1;
+    const TMAX: u32 = 26;
+    const SKEW: u32 = 38;
+    const DAMP: u32 = 700;
+    const INITIAL_BIAS: u32 = 72;
+    const INITIAL_N: u32 = 0x80;
+
+    fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+        let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+        delta += delta / num_points;
+
+        let mut k = 0;
+        while delta > ((BASE - TMIN) * TMAX) / 2 {
+            delta /= BASE - TMIN;
+            k += BASE;
+        }
+
+        k + (((BASE - TMIN + 1) * delta) / (delta + SKEW))
+    }
+
+    fn digit_to_char(d: u32) -> char {
+        if d < 26 {
+            (b'a' + d as u8) as char
+        } else {
+            (b'0' + (d - 26) as u8) as char
+        }
+    }
+
+    let basic: String = label.chars().filter(|c| c.is_ascii()).collect();
+    let mut output = basic.clone();
+    let has_basic = !basic.is_empty();
+
+    let mut code_points: Vec<u32> = label.chars().map(|c| c as u32).collect();
+    code_points.sort_unstable();
+    code_points.dedup();
+    let non_ascii: Vec<u32> = code_points.into_iter().filter(|&c| c >= INITIAL_N).collect();
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic.chars().count() as u32;
+    let total = label.chars().count() as u32;
+    let mut first_time = true;
+
+    if has_basic {
+        output.push('-');
+    }
+
+    let mut remaining = non_ascii;
+    remaining.sort_unstable();
+
+    while handled < total {
+        let m = *remaining.iter().find(|&&c| c >= n)?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+
+        for c in label.chars().map(|c| c as u32) {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+
+                loop {
+                    let t = if k <= bias {
+                        TMIN
+                    } else if k >= bias + TMAX {
+                        TMAX
+                    } else {
+                        k - bias
+                    };
+
+                    if q < t {
+                        break;
+                    }
+
+                    output.push(digit_to_char(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+
+                output.push(digit_to_char(q));
+                bias = adapt(delta, handled + 1, first_time);
+                first_time = false;
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta += 1;
+        n += 1;
+    }
+
+    Some(format!("xn--{output}"))
+}
+
+/// The directory of `url`'s path: everything up to, but not including, the
+/// last `/`, or `/` if that `/` is the first character or there is none.
+/// Mirrors the default-path rule in RFC 6265 section 5.1.4.
+fn default_path_from_url(url: &str) -> String {
+    let rest = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let path_start = rest.find('/').unwrap_or(rest.len());
+    let path = &rest[path_start..];
+    let path = path.split(['?', '#']).next().unwrap_or("/");
+
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(idx) => path[..idx].to_string(),
+    }
+}
+
+/// Default maximum combined byte length of a cookie's name and value.
+pub const DEFAULT_MAX_COOKIE_SIZE: usize = 4096;
+
+/// Extract the host portion of a URL without pulling in a full URL-parsing
+/// dependency: strip the scheme, then take everything up to the next `/`,
+/// `?`, `#`, or `:` (port).
+fn host_from_url(url: &str) -> Option<&str> {
+    let rest = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let end = rest
+        .find(['/', '?', '#', ':'])
+        .unwrap_or(rest.len());
+    let host = &rest[..end];
+
+    if host.is_empty() { None } else { Some(host) }
 }
 
 /// Cookie visitor callback wrapper
@@ -239,6 +538,7 @@ where
                 2 => Priority::High,
                 _ => Priority::Medium,
             },
+            partition_key: if cookie.partition_key.is_null() { None } else { Some(c_str_to_string(cookie.partition_key)) },
         };
         
         // Call the user's callback
@@ -261,26 +561,525 @@ unsafe extern "C" fn cookie_visitor_destroy<F>(context: *mut c_void) {
     }
 }
 
-/// Helper to convert C string to Rust String
-unsafe fn c_str_to_string(ptr: *const c_char) -> String {
-    // SAFETY: We check for null before creating CStr
-    unsafe {
-        if ptr.is_null() {
-            String::new()
-        } else {
-            std::ffi::CStr::from_ptr(ptr)
-                .to_string_lossy()
-                .into_owned()
-        }
+/// Cookie visitor callback wrapper that also passes each cookie's
+/// last-access timestamp, used by [`CookieManager::garbage_collect`] since
+/// [`Cookie`] itself doesn't carry that field.
+struct CookieTimestampVisitorWrapper<F> {
+    callback: Arc<Mutex<F>>,
+}
+
+impl<F> CookieTimestampVisitorWrapper<F>
+where
+    F: FnMut(&Cookie, i64) -> bool,
+{
+    fn new(callback: F) -> Self {
+        Self {
+            callback: Arc::new(Mutex::new(callback)),
+        }
+    }
+}
+
+/// FFI callback for [`CookieTimestampVisitorWrapper`]
+unsafe extern "C" fn cookie_timestamp_visitor_visit<F>(
+    cookie: *const sys::Cookie,
+    _count: c_int,
+    _total: c_int,
+    _delete_cookie: *mut bool,
+    context: *mut c_void,
+) -> bool
+where
+    F: FnMut(&Cookie, i64) -> bool,
+{
+    // SAFETY: We check for null pointers before dereferencing
+    unsafe {
+        if cookie.is_null() || context.is_null() {
+            return false;
+        }
+
+        let wrapper = &*(context as *const CookieTimestampVisitorWrapper<F>);
+        let cookie = &*cookie;
+
+        let rust_cookie = Cookie {
+            name: c_str_to_string(cookie.name),
+            value: c_str_to_string(cookie.value),
+            domain: if cookie.domain.is_null() { None } else { Some(c_str_to_string(cookie.domain)) },
+            path: if cookie.path.is_null() { None } else { Some(c_str_to_string(cookie.path)) },
+            secure: cookie.secure,
+            httponly: cookie.httponly,
+            expires: if cookie.has_expires { Some(cookie.expires) } else { None },
+            same_site: match cookie.same_site {
+                0 => SameSite::Unspecified,
+                1 => SameSite::NoRestriction,
+                2 => SameSite::Lax,
+                3 => SameSite::Strict,
+                _ => SameSite::Unspecified,
+            },
+            priority: match cookie.priority {
+                0 => Priority::Low,
+                1 => Priority::Medium,
+                2 => Priority::High,
+                _ => Priority::Medium,
+            },
+            partition_key: if cookie.partition_key.is_null() { None } else { Some(c_str_to_string(cookie.partition_key)) },
+        };
+
+        if let Ok(mut callback) = wrapper.callback.lock() {
+            callback(&rust_cookie, cookie.last_access)
+        } else {
+            false
+        }
+    }
+}
+
+/// FFI callback for [`CookieTimestampVisitorWrapper`] destruction
+unsafe extern "C" fn cookie_timestamp_visitor_destroy<F>(context: *mut c_void) {
+    // SAFETY: We check for null before converting back to Box
+    unsafe {
+        if !context.is_null() {
+            let _ = Box::from_raw(context as *mut CookieTimestampVisitorWrapper<F>);
+        }
+    }
+}
+
+/// A boxed predicate used by [`CookieManager::delete_matching`]: given a
+/// cookie and its raw creation timestamp, returns whether it should be deleted.
+type CookieDeletePredicate = Box<dyn FnMut(&Cookie, i64) -> bool + Send>;
+
+/// FFI callback for `delete_matching`'s visitor: tests each cookie against
+/// the boxed predicate and marks it via the visitor's `delete_cookie`
+/// out-parameter rather than returning through the usual `Cookie` value.
+unsafe extern "C" fn cookie_delete_visitor_visit(
+    cookie: *const sys::Cookie,
+    _count: c_int,
+    _total: c_int,
+    delete_cookie: *mut bool,
+    context: *mut c_void,
+) -> bool {
+    // SAFETY: We check for null pointers before dereferencing
+    unsafe {
+        if cookie.is_null() || context.is_null() || delete_cookie.is_null() {
+            return true;
+        }
+
+        let predicate = &*(context as *const Mutex<CookieDeletePredicate>);
+        let cookie = &*cookie;
+
+        let rust_cookie = Cookie {
+            name: c_str_to_string(cookie.name),
+            value: c_str_to_string(cookie.value),
+            domain: if cookie.domain.is_null() { None } else { Some(c_str_to_string(cookie.domain)) },
+            path: if cookie.path.is_null() { None } else { Some(c_str_to_string(cookie.path)) },
+            secure: cookie.secure,
+            httponly: cookie.httponly,
+            expires: if cookie.has_expires { Some(cookie.expires) } else { None },
+            same_site: match cookie.same_site {
+                0 => SameSite::Unspecified,
+                1 => SameSite::NoRestriction,
+                2 => SameSite::Lax,
+                3 => SameSite::Strict,
+                _ => SameSite::Unspecified,
+            },
+            priority: match cookie.priority {
+                0 => Priority::Low,
+                1 => Priority::Medium,
+                2 => Priority::High,
+                _ => Priority::Medium,
+            },
+            partition_key: if cookie.partition_key.is_null() { None } else { Some(c_str_to_string(cookie.partition_key)) },
+        };
+
+        *delete_cookie = if let Ok(mut predicate) = predicate.lock() {
+            predicate(&rust_cookie, cookie.creation)
+        } else {
+            false
+        };
+
+        true
+    }
+}
+
+/// FFI callback for the delete visitor's destruction
+unsafe extern "C" fn cookie_delete_visitor_destroy(context: *mut c_void) {
+    // SAFETY: We check for null before converting back to Box
+    unsafe {
+        if !context.is_null() {
+            let _ = Box::from_raw(context as *mut Mutex<CookieDeletePredicate>);
+        }
+    }
+}
+
+/// Builder describing which cookies [`CookieManager::delete_matching`] should
+/// remove, modeled on Chromium's `CookieDeletionInfo`.
+#[derive(Debug, Clone, Default)]
+pub struct DeletionFilter {
+    /// Only match cookies created at or after this time (epoch seconds)
+    pub created_after: Option<i64>,
+    /// Only match cookies created at or before this time (epoch seconds)
+    pub created_before: Option<i64>,
+    /// If set, only match cookies whose domain is in this set
+    pub domains: Option<HashSet<String>>,
+    /// If set, never match cookies whose domain is in this set
+    pub excluded_domains: Option<HashSet<String>>,
+    /// If set, only match cookies with this exact name
+    pub name: Option<String>,
+    /// If true, only match cookies with no expiration (session cookies)
+    pub session_only: bool,
+}
+
+impl DeletionFilter {
+    /// Create an empty filter that matches every cookie
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match cookies created at or after `timestamp` (epoch seconds)
+    pub fn created_after(mut self, timestamp: i64) -> Self {
+        self.created_after = Some(timestamp);
+        self
+    }
+
+    /// Only match cookies created at or before `timestamp` (epoch seconds)
+    pub fn created_before(mut self, timestamp: i64) -> Self {
+        self.created_before = Some(timestamp);
+        self
+    }
+
+    /// Only match cookies whose domain is in `domains`
+    pub fn domains(mut self, domains: HashSet<String>) -> Self {
+        self.domains = Some(domains);
+        self
+    }
+
+    /// Never match cookies whose domain is in `excluded_domains`
+    pub fn excluded_domains(mut self, excluded_domains: HashSet<String>) -> Self {
+        self.excluded_domains = Some(excluded_domains);
+        self
+    }
+
+    /// Only match cookies named `name`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Only match session cookies (no expiration)
+    pub fn session_only(mut self, session_only: bool) -> Self {
+        self.session_only = session_only;
+        self
+    }
+
+    fn matches(&self, cookie: &Cookie, creation: i64) -> bool {
+        if let Some(after) = self.created_after {
+            if creation < after {
+                return false;
+            }
+        }
+
+        if let Some(before) = self.created_before {
+            if creation > before {
+                return false;
+            }
+        }
+
+        if let Some(domains) = &self.domains {
+            match &cookie.domain {
+                Some(domain) if domains.contains(domain) => {}
+                _ => return false,
+            }
+        }
+
+        if let Some(excluded) = &self.excluded_domains {
+            if let Some(domain) = &cookie.domain {
+                if excluded.contains(domain) {
+                    return false;
+                }
+            }
+        }
+
+        if let Some(name) = &self.name {
+            if &cookie.name != name {
+                return false;
+            }
+        }
+
+        if self.session_only && cookie.expires.is_some() {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// Helper to convert C string to Rust String
+unsafe fn c_str_to_string(ptr: *const c_char) -> String {
+    // SAFETY: We check for null before creating CStr
+    unsafe {
+        if ptr.is_null() {
+            String::new()
+        } else {
+            std::ffi::CStr::from_ptr(ptr)
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+}
+
+/// Reason a cookie changed, reported alongside each change notification.
+///
+/// Mirrors Chromium's `net::CookieChangeCause`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeCause {
+    /// A new cookie was inserted where none existed before
+    Inserted,
+    /// The cookie was removed by an explicit call (e.g. `delete_cookie`)
+    Explicit,
+    /// The cookie was removed because a new cookie overwrote it
+    Overwrite,
+    /// The cookie was removed because it expired
+    Expired,
+    /// The cookie was removed to make room under a size/count limit
+    Evicted,
+}
+
+/// Optional filter narrowing which cookie changes a listener is notified about.
+///
+/// Any field left `None` matches every cookie for that attribute.
+#[derive(Debug, Clone, Default)]
+pub struct ChangeFilter {
+    /// Only notify about changes to cookies visible to this URL
+    pub url: Option<String>,
+    /// Only notify about changes to cookies for this domain
+    pub domain: Option<String>,
+    /// Only notify about changes to the cookie with this name
+    pub name: Option<String>,
+}
+
+impl ChangeFilter {
+    /// Create an empty filter that matches every cookie change
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict notifications to cookies visible to `url`
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    /// Restrict notifications to cookies for `domain`
+    pub fn domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Restrict notifications to the cookie named `name`
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+/// Cookie change listener callback wrapper
+struct CookieChangeListenerWrapper<F> {
+    callback: Arc<Mutex<F>>,
+}
+
+impl<F> CookieChangeListenerWrapper<F>
+where
+    F: FnMut(&Cookie, ChangeCause) + Send,
+{
+    fn new(callback: F) -> Self {
+        Self {
+            callback: Arc::new(Mutex::new(callback)),
+        }
+    }
+}
+
+/// FFI callback invoked on the IO thread when a matching cookie changes
+unsafe extern "C" fn cookie_change_observer_on_change<F>(
+    cookie: *const sys::Cookie,
+    cause: c_int,
+    context: *mut c_void,
+) where
+    F: FnMut(&Cookie, ChangeCause) + Send,
+{
+    // SAFETY: We check for null pointers before dereferencing
+    unsafe {
+        if cookie.is_null() || context.is_null() {
+            return;
+        }
+
+        let wrapper = &*(context as *const CookieChangeListenerWrapper<F>);
+        let cookie = &*cookie;
+
+        let rust_cookie = Cookie {
+            name: c_str_to_string(cookie.name),
+            value: c_str_to_string(cookie.value),
+            domain: if cookie.domain.is_null() { None } else { Some(c_str_to_string(cookie.domain)) },
+            path: if cookie.path.is_null() { None } else { Some(c_str_to_string(cookie.path)) },
+            secure: cookie.secure,
+            httponly: cookie.httponly,
+            expires: if cookie.has_expires { Some(cookie.expires) } else { None },
+            same_site: match cookie.same_site {
+                0 => SameSite::Unspecified,
+                1 => SameSite::NoRestriction,
+                2 => SameSite::Lax,
+                3 => SameSite::Strict,
+                _ => SameSite::Unspecified,
+            },
+            priority: match cookie.priority {
+                0 => Priority::Low,
+                1 => Priority::Medium,
+                2 => Priority::High,
+                _ => Priority::Medium,
+            },
+            partition_key: if cookie.partition_key.is_null() { None } else { Some(c_str_to_string(cookie.partition_key)) },
+        };
+
+        let cause = match cause {
+            0 => ChangeCause::Inserted,
+            1 => ChangeCause::Explicit,
+            2 => ChangeCause::Overwrite,
+            3 => ChangeCause::Expired,
+            4 => ChangeCause::Evicted,
+            _ => ChangeCause::Explicit,
+        };
+
+        if let Ok(mut callback) = wrapper.callback.lock() {
+            callback(&rust_cookie, cause);
+        }
+    }
+}
+
+/// FFI callback for cookie change listener destruction
+unsafe extern "C" fn cookie_change_observer_destroy<F>(context: *mut c_void) {
+    // SAFETY: We check for null before converting back to Box
+    unsafe {
+        if !context.is_null() {
+            let _ = Box::from_raw(context as *mut CookieChangeListenerWrapper<F>);
+        }
+    }
+}
+
+/// Handle to a registered cookie change listener.
+///
+/// Dropping this handle unregisters the listener; it will no longer receive
+/// change notifications afterward.
+pub struct ListenerHandle {
+    inner: ThreadSafePointer<c_void>,
+}
+
+impl Drop for ListenerHandle {
+    fn drop(&mut self) {
+        unsafe {
+            sys::wew_remove_cookie_change_listener(self.inner.as_ptr());
+        }
+    }
+}
+
+unsafe impl Send for ListenerHandle {}
+unsafe impl Sync for ListenerHandle {}
+
+/// Caps driving [`CookieManager::garbage_collect`], defaulting to the
+/// numbers Chromium's CookieMonster uses.
+#[derive(Debug, Clone, Copy)]
+pub struct EvictionLimits {
+    /// Per-domain cookie count that triggers eviction
+    pub domain_max: usize,
+    /// Per-domain count to purge down to once `domain_max` is exceeded
+    pub domain_target: usize,
+    /// Store-wide cookie count that triggers eviction
+    pub global_max: usize,
+    /// Store-wide count to purge down to once `global_max` is exceeded
+    pub global_target: usize,
+    /// Highest-priority, most-recently-used cookies (per domain, and again
+    /// globally) that are never evicted
+    pub protected_high_priority: usize,
+}
+
+impl Default for EvictionLimits {
+    fn default() -> Self {
+        Self {
+            domain_max: 180,
+            domain_target: 150,
+            global_max: 3300,
+            global_target: 3000,
+            protected_high_priority: 30,
+        }
+    }
+}
+
+/// Pick cookies to evict from `candidates` (indices into `records`) so that
+/// at most `target` of them remain, preferring to evict lowest [`Priority`]
+/// first and, within a priority, the least-recently-accessed first. The
+/// `protected_high_priority` most-recently-used [`Priority::High`] cookies
+/// are never evicted. Marks chosen indices in `evicted`.
+fn select_for_eviction(
+    records: &[(Cookie, i64)],
+    candidates: &[usize],
+    max: usize,
+    target: usize,
+    protected_high_priority: usize,
+    evicted: &mut [bool],
+) {
+    if candidates.len() <= max {
+        return;
+    }
+
+    let mut protected = HashSet::new();
+    let mut high_priority: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|&i| records[i].0.priority == Priority::High)
+        .collect();
+    high_priority.sort_by_key(|&i| std::cmp::Reverse(records[i].1));
+    protected.extend(high_priority.into_iter().take(protected_high_priority));
+
+    let mut ordered: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|i| !protected.contains(i))
+        .collect();
+    ordered.sort_by(|&a, &b| {
+        records[a]
+            .0
+            .priority
+            .cmp(&records[b].0.priority)
+            .then(records[a].1.cmp(&records[b].1))
+    });
+
+    let remaining_after_protected = candidates.len() - protected.len();
+    let surviving_unprotected = target.saturating_sub(protected.len());
+    let to_evict = remaining_after_protected.saturating_sub(surviving_unprotected);
+
+    for &idx in ordered.iter().take(to_evict) {
+        evicted[idx] = true;
     }
 }
 
+/// Reconstruct a URL a cookie could have been set against, from its
+/// domain/path/secure flags. Used where a cookie needs to be re-submitted
+/// (import) or deleted (garbage collection) without the original request URL.
+fn cookie_url(cookie: &Cookie) -> String {
+    let scheme = if cookie.secure { "https" } else { "http" };
+    let host = cookie.domain.as_deref().unwrap_or("localhost").trim_start_matches('.');
+    let path = cookie.path.as_deref().unwrap_or("/");
+
+    format!("{scheme}://{host}{path}")
+}
+
 /// Cookie manager for managing browser cookies
 pub struct CookieManager {
     inner: ThreadSafePointer<c_void>,
 }
 
 impl CookieManager {
+    /// Obtain a view over this manager that transparently encrypts (or
+    /// signs) cookie values using `key`. See [`PrivateCookies`].
+    pub fn private<'a>(&'a self, key: &Key) -> PrivateCookies<'a> {
+        PrivateCookies::new(self, key)
+    }
+
     /// Get the global cookie manager instance
     pub fn global() -> Self {
         unsafe {
@@ -293,6 +1092,9 @@ impl CookieManager {
 
     /// Set a cookie for the specified URL
     pub fn set_cookie(&self, url: &str, cookie: &Cookie) -> Result<(), CookieError> {
+        let cookie = cookie.canonicalize(url)?;
+        cookie.validate_for(url)?;
+
         let c_url = CString::new(url).map_err(|_| CookieError::InvalidUrl)?;
         let c_name = CString::new(cookie.name.as_str()).map_err(|_| CookieError::InvalidCookieName)?;
         let c_value = CString::new(cookie.value.as_str()).map_err(|_| CookieError::InvalidCookieValue)?;
@@ -307,6 +1109,11 @@ impl CookieManager {
             .transpose()
             .map_err(|_| CookieError::InvalidPath)?;
 
+        let c_partition_key = cookie.partition_key.as_ref()
+            .map(|k| CString::new(k.as_str()))
+            .transpose()
+            .map_err(|_| CookieError::InvalidDomain)?;
+
         // Get current time for creation/last_access if not specified
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -326,6 +1133,7 @@ impl CookieManager {
             last_access: now,
             same_site: cookie.same_site as i32,
             priority: cookie.priority as i32,
+            partition_key: c_partition_key.as_ref().map(|k| k.as_ptr()).unwrap_or(null_mut()),
         };
 
         unsafe {
@@ -367,6 +1175,38 @@ impl CookieManager {
         }
     }
 
+    /// Delete every cookie matching `filter`, modeled on Chromium's
+    /// `CookieDeletionInfo`. Returns the number of cookies removed.
+    pub fn delete_matching(&self, filter: &DeletionFilter) -> Result<u32, CookieError> {
+        let count = Arc::new(AtomicU32::new(0));
+        let matched_count = count.clone();
+        let filter = filter.clone();
+
+        let predicate: CookieDeletePredicate = Box::new(move |cookie, creation| {
+            if filter.matches(cookie, creation) {
+                matched_count.fetch_add(1, Ordering::Relaxed);
+                true
+            } else {
+                false
+            }
+        });
+
+        let wrapper = Box::new(Mutex::new(predicate));
+        let wrapper_ptr = Box::into_raw(wrapper);
+
+        let visitor = sys::CookieVisitor {
+            visit: Some(cookie_delete_visitor_visit),
+            destroy: Some(cookie_delete_visitor_destroy),
+            context: wrapper_ptr as *mut c_void,
+        };
+
+        unsafe {
+            sys::wew_visit_all_cookies(self.inner.as_ptr(), &visitor as *const _ as *mut _);
+        }
+
+        Ok(count.load(Ordering::Relaxed))
+    }
+
     /// Flush the cookie store to disk
     pub fn flush_store(&self) -> Result<(), CookieError> {
         unsafe {
@@ -428,6 +1268,277 @@ impl CookieManager {
             );
         }
     }
+
+    /// Fetch the single cookie named `name` visible to `url`, if present.
+    ///
+    /// A convenience wrapper over [`CookieManager::visit_url_cookies`] for
+    /// the common case of looking up one cookie (e.g. a session token)
+    /// rather than iterating the whole store.
+    pub fn get_cookie(&self, url: &str, name: &str) -> Option<Cookie> {
+        let found = Arc::new(Mutex::new(None));
+        let collected = found.clone();
+        let target = name.to_string();
+
+        self.visit_url_cookies(url, true, move |cookie| {
+            if cookie.name == target {
+                *collected.lock().unwrap() = Some(cookie.clone());
+                return false;
+            }
+            true
+        });
+
+        Arc::try_unwrap(found).ok()?.into_inner().ok()?
+    }
+
+    /// Visit only cookies partitioned under `partition_key` (CHIPS).
+    /// The callback receives each cookie and should return true to continue visiting
+    pub fn visit_partitioned_cookies<F>(&self, partition_key: &str, callback: F)
+    where
+        F: FnMut(&Cookie) -> bool + Send + 'static,
+    {
+        let c_partition_key = match CString::new(partition_key) {
+            Ok(key) => key,
+            Err(_) => return,
+        };
+
+        let wrapper = Box::new(CookieVisitorWrapper::new(callback));
+        let wrapper_ptr = Box::into_raw(wrapper);
+
+        let visitor = sys::CookieVisitor {
+            visit: Some(cookie_visitor_visit::<F>),
+            destroy: Some(cookie_visitor_destroy::<F>),
+            context: wrapper_ptr as *mut c_void,
+        };
+
+        unsafe {
+            sys::wew_visit_partitioned_cookies(
+                self.inner.as_ptr(),
+                c_partition_key.as_ptr(),
+                &visitor as *const _ as *mut _,
+            );
+        }
+    }
+
+    /// Register a listener that fires whenever a cookie matching `filter` is
+    /// inserted, updated, or removed.
+    ///
+    /// Drop the returned [`ListenerHandle`] to stop receiving notifications.
+    pub fn add_change_listener<F>(&self, filter: Option<ChangeFilter>, callback: F) -> ListenerHandle
+    where
+        F: FnMut(&Cookie, ChangeCause) + Send + 'static,
+    {
+        let wrapper = Box::new(CookieChangeListenerWrapper::new(callback));
+        let wrapper_ptr = Box::into_raw(wrapper);
+
+        let c_url = filter.as_ref().and_then(|f| f.url.as_deref()).and_then(|u| CString::new(u).ok());
+        let c_domain = filter.as_ref().and_then(|f| f.domain.as_deref()).and_then(|d| CString::new(d).ok());
+        let c_name = filter.as_ref().and_then(|f| f.name.as_deref()).and_then(|n| CString::new(n).ok());
+
+        let sys_filter = sys::CookieChangeFilter {
+            url: c_url.as_ref().map(|s| s.as_ptr()).unwrap_or(null_mut()),
+            domain: c_domain.as_ref().map(|s| s.as_ptr()).unwrap_or(null_mut()),
+            name: c_name.as_ref().map(|s| s.as_ptr()).unwrap_or(null_mut()),
+        };
+
+        let observer = sys::CookieChangeObserver {
+            on_change: Some(cookie_change_observer_on_change::<F>),
+            destroy: Some(cookie_change_observer_destroy::<F>),
+            context: wrapper_ptr as *mut c_void,
+        };
+
+        unsafe {
+            let handle = sys::wew_add_cookie_change_listener(
+                self.inner.as_ptr(),
+                &sys_filter as *const _ as *mut _,
+                &observer as *const _ as *mut _,
+            );
+
+            ListenerHandle {
+                inner: ThreadSafePointer::new(handle),
+            }
+        }
+    }
+
+    /// Collect every cookie in the store into a `Vec`, built on top of
+    /// [`CookieManager::visit_all_cookies`].
+    pub fn export_cookies(&self) -> Vec<Cookie> {
+        let cookies = Arc::new(Mutex::new(Vec::new()));
+        let collected = cookies.clone();
+
+        self.visit_all_cookies(move |cookie| {
+            collected.lock().unwrap().push(cookie.clone());
+            true
+        });
+
+        Arc::try_unwrap(cookies)
+            .map(|m| m.into_inner().unwrap())
+            .unwrap_or_default()
+    }
+
+    /// Re-insert a previously exported cookie, reconstructing a URL from its
+    /// domain/path/secure flags.
+    fn import_cookie(&self, cookie: &Cookie) -> Result<(), CookieError> {
+        self.set_cookie(&cookie_url(cookie), cookie)
+    }
+
+    /// Run CookieMonster-style garbage collection over the store: when a
+    /// domain exceeds `limits.domain_max` cookies, or the whole store
+    /// exceeds `limits.global_max`, evict cookies in ascending [`Priority`]
+    /// order (lowest priority first) and, within the same priority,
+    /// least-recently-accessed first, down to the corresponding target.
+    /// `limits.protected_high_priority` highest-priority, most-recently-used
+    /// cookies per scope are never evicted. Returns the number removed.
+    pub fn garbage_collect(&self, limits: EvictionLimits) -> u32 {
+        let collected = Arc::new(Mutex::new(Vec::new()));
+        let sink = collected.clone();
+
+        self.visit_all_with_last_access(move |cookie, last_access| {
+            sink.lock().unwrap().push((cookie.clone(), last_access));
+            true
+        });
+
+        let records = Arc::try_unwrap(collected)
+            .map(|m| m.into_inner().unwrap())
+            .unwrap_or_default();
+
+        let mut by_domain: std::collections::HashMap<String, Vec<usize>> =
+            std::collections::HashMap::new();
+        for (idx, (cookie, _)) in records.iter().enumerate() {
+            by_domain
+                .entry(cookie.domain.clone().unwrap_or_default())
+                .or_default()
+                .push(idx);
+        }
+
+        let mut evicted = vec![false; records.len()];
+
+        for indices in by_domain.values() {
+            select_for_eviction(
+                &records,
+                indices,
+                limits.domain_max,
+                limits.domain_target,
+                limits.protected_high_priority,
+                &mut evicted,
+            );
+        }
+
+        let remaining: Vec<usize> = (0..records.len()).filter(|&i| !evicted[i]).collect();
+        select_for_eviction(
+            &records,
+            &remaining,
+            limits.global_max,
+            limits.global_target,
+            limits.protected_high_priority,
+            &mut evicted,
+        );
+
+        let mut removed = 0u32;
+        for (idx, (cookie, _)) in records.iter().enumerate() {
+            if evicted[idx] && self.delete_cookie(&cookie_url(cookie), Some(&cookie.name)).is_ok()
+            {
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    /// Like [`CookieManager::visit_all_cookies`], but also passes each
+    /// cookie's raw last-access timestamp, which isn't part of [`Cookie`].
+    fn visit_all_with_last_access<F>(&self, callback: F)
+    where
+        F: FnMut(&Cookie, i64) -> bool + Send + 'static,
+    {
+        let wrapper = Box::new(CookieTimestampVisitorWrapper::new(callback));
+        let wrapper_ptr = Box::into_raw(wrapper);
+
+        let visitor = sys::CookieVisitor {
+            visit: Some(cookie_timestamp_visitor_visit::<F>),
+            destroy: Some(cookie_timestamp_visitor_destroy::<F>),
+            context: wrapper_ptr as *mut c_void,
+        };
+
+        unsafe {
+            sys::wew_visit_all_cookies(self.inner.as_ptr(), &visitor as *const _ as *mut _);
+        }
+    }
+
+    /// Serialize every cookie in the store to `w` as a JSON array.
+    #[cfg(feature = "serde")]
+    pub fn save_json<W: Write>(&self, w: W) -> serde_json::Result<()> {
+        serde_json::to_writer(w, &self.export_cookies())
+    }
+
+    /// Load a JSON array of cookies previously written by [`CookieManager::save_json`],
+    /// re-inserting each one via `set_cookie`.
+    #[cfg(feature = "serde")]
+    pub fn load_json<R: io::Read>(&self, r: R) -> serde_json::Result<()> {
+        let cookies: Vec<Cookie> = serde_json::from_reader(r)?;
+        for cookie in &cookies {
+            let _ = self.import_cookie(cookie);
+        }
+        Ok(())
+    }
+
+    /// Write every cookie in the store to `w` using the Netscape `cookies.txt`
+    /// tab-separated format.
+    pub fn save_netscape<W: Write>(&self, mut w: W) -> io::Result<()> {
+        writeln!(w, "# Netscape HTTP Cookie File")?;
+
+        for cookie in self.export_cookies() {
+            let domain = cookie.domain.clone().unwrap_or_default();
+            let include_subdomains = domain.starts_with('.');
+            let path = cookie.path.clone().unwrap_or_else(|| "/".to_string());
+            let expires = cookie.expires.unwrap_or(0);
+
+            writeln!(
+                w,
+                "{domain}\t{flag}\t{path}\t{secure}\t{expires}\t{name}\t{value}",
+                flag = if include_subdomains { "TRUE" } else { "FALSE" },
+                secure = if cookie.secure { "TRUE" } else { "FALSE" },
+                name = cookie.name,
+                value = cookie.value,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Load cookies from the Netscape `cookies.txt` tab-separated format,
+    /// re-inserting each one via `set_cookie`.
+    pub fn load_netscape<R: io::Read>(&self, r: R) -> io::Result<()> {
+        for line in BufReader::new(r).lines() {
+            let line = line?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != 7 {
+                continue;
+            }
+
+            let cookie = Cookie {
+                name: fields[5].to_string(),
+                value: fields[6].to_string(),
+                domain: Some(fields[0].to_string()),
+                path: Some(fields[2].to_string()),
+                secure: fields[3].eq_ignore_ascii_case("true"),
+                httponly: false,
+                expires: fields[4].parse::<i64>().ok().filter(|&e| e != 0),
+                same_site: SameSite::Unspecified,
+                priority: Priority::Medium,
+                partition_key: None,
+            };
+
+            let _ = self.import_cookie(&cookie);
+        }
+
+        Ok(())
+    }
 }
 
 impl Drop for CookieManager {
@@ -441,6 +1552,183 @@ impl Drop for CookieManager {
 unsafe impl Send for CookieManager {}
 unsafe impl Sync for CookieManager {}
 
+/// Key material backing [`PrivateCookies`]: 512 bits split into a 256-bit
+/// AES-256-GCM encryption key and a 256-bit HMAC-SHA256 signing key.
+///
+/// Mirrors the role Rocket's `CookieJar::private` key and actix's
+/// secure-cookies key play: callers generate one at startup and keep it
+/// stable across restarts so existing cookies remain decryptable.
+#[derive(Clone)]
+pub struct Key {
+    encryption: [u8; 32],
+    signing: [u8; 32],
+}
+
+impl Key {
+    /// Derive a `Key` from 64 bytes of caller-supplied key material. The
+    /// first 32 bytes are used for encryption, the last 32 for signing.
+    pub fn from(bytes: [u8; 64]) -> Self {
+        let mut encryption = [0u8; 32];
+        let mut signing = [0u8; 32];
+        encryption.copy_from_slice(&bytes[..32]);
+        signing.copy_from_slice(&bytes[32..]);
+        Self { encryption, signing }
+    }
+
+    /// Generate a new random `Key` using the operating system's CSPRNG.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 64];
+        rand::rng().fill_bytes(&mut bytes);
+        Self::from(bytes)
+    }
+}
+
+/// A view over a [`CookieManager`] that transparently encrypts (or signs)
+/// cookie values, modeled on Rocket's `CookieJar::private`.
+///
+/// Obtained via [`CookieManager::private`]. Values set through this wrapper
+/// are authenticated-encrypted with AES-256-GCM (a random 96-bit nonce,
+/// stored as `base64(nonce || ciphertext || tag)`, with the cookie name
+/// bound in as associated data to prevent value-swapping between cookies).
+/// Values that fail to decrypt or authenticate are treated as absent rather
+/// than returned to the caller.
+pub struct PrivateCookies<'a> {
+    manager: &'a CookieManager,
+    key: Key,
+}
+
+impl<'a> PrivateCookies<'a> {
+    fn new(manager: &'a CookieManager, key: &Key) -> Self {
+        Self { manager, key: key.clone() }
+    }
+
+    /// Encrypt `cookie`'s value and store it for `url`.
+    pub fn set(&self, url: &str, mut cookie: Cookie) -> Result<(), CookieError> {
+        cookie.value = self.encrypt(&cookie.name, &cookie.value);
+        self.manager.set_cookie(url, &cookie)
+    }
+
+    /// Fetch and decrypt the cookie named `name` for `url`, if present and
+    /// authentic.
+    pub fn get(&self, url: &str, name: &str) -> Option<Cookie> {
+        let found = Arc::new(Mutex::new(None));
+        let collected = found.clone();
+        let target = name.to_string();
+
+        self.manager.visit_url_cookies(url, true, move |cookie| {
+            if cookie.name == target {
+                *collected.lock().unwrap() = Some(cookie.clone());
+                return false;
+            }
+            true
+        });
+
+        let mut cookie = Arc::try_unwrap(found).ok()?.into_inner().ok()??;
+        cookie.value = self.decrypt(&cookie.name, &cookie.value)?;
+        Some(cookie)
+    }
+
+    /// Sign (but do not encrypt) `cookie`'s value and store it for `url`.
+    ///
+    /// Use this when the value need not be confidential but must be
+    /// tamper-evident.
+    pub fn set_signed(&self, url: &str, mut cookie: Cookie) -> Result<(), CookieError> {
+        cookie.value = self.sign(&cookie.name, &cookie.value);
+        self.manager.set_cookie(url, &cookie)
+    }
+
+    /// Fetch and verify the signature of the cookie named `name` for `url`.
+    pub fn get_signed(&self, url: &str, name: &str) -> Option<Cookie> {
+        let found = Arc::new(Mutex::new(None));
+        let collected = found.clone();
+        let target = name.to_string();
+
+        self.manager.visit_url_cookies(url, true, move |cookie| {
+            if cookie.name == target {
+                *collected.lock().unwrap() = Some(cookie.clone());
+                return false;
+            }
+            true
+        });
+
+        let mut cookie = Arc::try_unwrap(found).ok()?.into_inner().ok()??;
+        cookie.value = self.verify(&cookie.name, &cookie.value)?;
+        Some(cookie)
+    }
+
+    fn encrypt(&self, name: &str, value: &str) -> String {
+        use aes_gcm::{
+            aead::{Aead, KeyInit, Payload},
+            Aes256Gcm, Nonce,
+        };
+
+        let cipher = Aes256Gcm::new_from_slice(&self.key.encryption).expect("key is 32 bytes");
+
+        let mut nonce_bytes = [0u8; 12];
+        rand::rng().fill_bytes(&mut nonce_bytes);
+        let nonce = Nonce::from_slice(&nonce_bytes);
+
+        let ciphertext = cipher
+            .encrypt(nonce, Payload { msg: value.as_bytes(), aad: name.as_bytes() })
+            .expect("encryption does not fail for valid inputs");
+
+        let mut out = Vec::with_capacity(nonce_bytes.len() + ciphertext.len());
+        out.extend_from_slice(&nonce_bytes);
+        out.extend_from_slice(&ciphertext);
+
+        BASE64_STANDARD.encode(out)
+    }
+
+    fn decrypt(&self, name: &str, value: &str) -> Option<String> {
+        use aes_gcm::{
+            aead::{Aead, KeyInit, Payload},
+            Aes256Gcm, Nonce,
+        };
+
+        let raw = BASE64_STANDARD.decode(value).ok()?;
+        if raw.len() < 12 {
+            return None;
+        }
+
+        let (nonce_bytes, ciphertext) = raw.split_at(12);
+        let cipher = Aes256Gcm::new_from_slice(&self.key.encryption).ok()?;
+        let nonce = Nonce::from_slice(nonce_bytes);
+
+        let plaintext = cipher
+            .decrypt(nonce, Payload { msg: ciphertext, aad: name.as_bytes() })
+            .ok()?;
+
+        String::from_utf8(plaintext).ok()
+    }
+
+    fn sign(&self, name: &str, value: &str) -> String {
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key.signing).expect("key is 32 bytes");
+        mac.update(name.as_bytes());
+        mac.update(value.as_bytes());
+        let tag = BASE64_STANDARD.encode(mac.finalize().into_bytes());
+
+        format!("{tag}.{value}")
+    }
+
+    fn verify(&self, name: &str, value: &str) -> Option<String> {
+        use hmac::{Hmac, Mac};
+        use sha2::Sha256;
+
+        let (tag, value) = value.split_once('.')?;
+        let expected_tag = BASE64_STANDARD.decode(tag).ok()?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key.signing).ok()?;
+        mac.update(name.as_bytes());
+        mac.update(value.as_bytes());
+        mac.verify_slice(&expected_tag).ok()?;
+
+        Some(value.to_string())
+    }
+}
+
 /// Errors that can occur during cookie operations
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CookieError {
@@ -460,6 +1748,13 @@ pub enum CookieError {
     DeleteCookieFailed,
     /// Failed to flush cookie store
     FlushStoreFailed,
+    /// A `__Secure-`/`__Host-` prefixed cookie did not satisfy the
+    /// attributes that prefix requires
+    PrefixViolation,
+    /// The cookie's domain is not a valid suffix match of the target URL's host
+    DomainMismatch,
+    /// The cookie's name+value length exceeds the configured byte cap
+    TooLarge,
 }
 
 impl std::fmt::Display for CookieError {
@@ -473,12 +1768,190 @@ impl std::fmt::Display for CookieError {
             CookieError::SetCookieFailed => write!(f, "Failed to set cookie"),
             CookieError::DeleteCookieFailed => write!(f, "Failed to delete cookie"),
             CookieError::FlushStoreFailed => write!(f, "Failed to flush cookie store"),
+            CookieError::PrefixViolation => write!(f, "Cookie name prefix requirements not satisfied"),
+            CookieError::DomainMismatch => write!(f, "Cookie domain does not match the target URL's host"),
+            CookieError::TooLarge => write!(f, "Cookie name and value exceed the maximum allowed size"),
         }
     }
 }
 
 impl std::error::Error for CookieError {}
 
+/// A cookie jar that can be driven from raw HTTP headers, analogous to
+/// reqwest's `cookie::CookieStore` trait.
+///
+/// This lets an embedder reuse CEF's cookie store for out-of-band HTTP
+/// requests made by Rust code, keeping one coherent jar across the webview
+/// and native fetches.
+pub trait CookieStore {
+    /// Parse each `Set-Cookie` header value in `headers` and store the
+    /// resulting cookie against `url`.
+    fn set_cookies(&self, headers: &mut dyn Iterator<Item = &str>, url: &str);
+
+    /// Serialize the cookies applicable to `url` into a single `Cookie:`
+    /// request-header value, or `None` if there are none.
+    fn cookies(&self, url: &str) -> Option<String>;
+}
+
+impl CookieStore for CookieManager {
+    fn set_cookies(&self, headers: &mut dyn Iterator<Item = &str>, url: &str) {
+        for header in headers {
+            if let Some(cookie) = parse_set_cookie_header(header, url) {
+                let _ = self.set_cookie(url, &cookie);
+            }
+        }
+    }
+
+    fn cookies(&self, url: &str) -> Option<String> {
+        let matching = Arc::new(Mutex::new(Vec::new()));
+        let collected = matching.clone();
+
+        self.visit_url_cookies(url, true, move |cookie| {
+            collected.lock().unwrap().push(cookie.clone());
+            true
+        });
+
+        let mut cookies = Arc::try_unwrap(matching).ok()?.into_inner().ok()?;
+        if cookies.is_empty() {
+            return None;
+        }
+
+        // RFC 6265 section 5.4: cookies with longer paths are sent first.
+        cookies.sort_by(|a, b| {
+            let a_len = a.path.as_deref().unwrap_or("/").len();
+            let b_len = b.path.as_deref().unwrap_or("/").len();
+            b_len.cmp(&a_len)
+        });
+
+        Some(
+            cookies
+                .iter()
+                .map(|c| format!("{}={}", c.name, c.value))
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+}
+
+/// Parse a raw `Set-Cookie` header value (`name=value` plus any number of
+/// `Domain=`/`Path=`/`Secure`/`HttpOnly`/`Max-Age=`/`Expires=`/`SameSite=`
+/// attributes) into a [`Cookie`], honoring `__Host-`/`__Secure-` prefixes.
+fn parse_set_cookie_header(header: &str, url: &str) -> Option<Cookie> {
+    let mut parts = header.split(';').map(str::trim);
+    let (name, value) = parts.next()?.split_once('=')?;
+    let name = name.trim();
+    let value = value.trim().trim_matches('"');
+
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut cookie = Cookie::new(name, value);
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    for attr in parts {
+        let (key, val) = attr.split_once('=').map(|(k, v)| (k, Some(v))).unwrap_or((attr, None));
+        let key_lower = key.trim().to_ascii_lowercase();
+
+        match key_lower.as_str() {
+            "domain" => {
+                if let Some(val) = val {
+                    cookie = cookie.domain(val.trim().to_string());
+                }
+            }
+            "path" => {
+                if let Some(val) = val {
+                    cookie = cookie.path(val.trim().to_string());
+                }
+            }
+            "secure" => cookie = cookie.secure(true),
+            "httponly" => cookie = cookie.httponly(true),
+            "max-age" => {
+                if let Some(val) = val.and_then(|v| v.trim().parse::<i64>().ok()) {
+                    cookie = cookie.expires_at(now + val);
+                }
+            }
+            "expires" => {
+                if let Some(val) = val.and_then(|v| parse_http_date(v.trim())) {
+                    cookie = cookie.expires_at(val);
+                }
+            }
+            "samesite" => {
+                let same_site = match val.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+                    Some("strict") => SameSite::Strict,
+                    Some("lax") => SameSite::Lax,
+                    Some("none") => SameSite::NoRestriction,
+                    _ => SameSite::Unspecified,
+                };
+                cookie = cookie.same_site(same_site);
+            }
+            _ => {}
+        }
+    }
+
+    if name.starts_with("__Secure-") {
+        cookie = cookie.secure(true);
+    }
+
+    if name.starts_with("__Host-") {
+        cookie = cookie.secure(true).path("/");
+        cookie.domain = None;
+    }
+
+    if cookie.validate_for(url).is_err() {
+        return None;
+    }
+
+    Some(cookie)
+}
+
+/// Parse a minimal subset of HTTP-date (RFC 7231 section 7.1.1.1's IMF-fixdate,
+/// e.g. `Wed, 21 Oct 2026 07:28:00 GMT`) into epoch seconds.
+fn parse_http_date(value: &str) -> Option<i64> {
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    if parts.len() != 6 {
+        return None;
+    }
+
+    let day: i64 = parts[1].parse().ok()?;
+    let month = match parts[2].to_ascii_lowercase().as_str() {
+        "jan" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "apr" => 4,
+        "may" => 5,
+        "jun" => 6,
+        "jul" => 7,
+        "aug" => 8,
+        "sep" => 9,
+        "oct" => 10,
+        "nov" => 11,
+        "dec" => 12,
+        _ => return None,
+    };
+    let year: i64 = parts[3].parse().ok()?;
+
+    let mut time = parts[4].split(':');
+    let hour: i64 = time.next()?.parse().ok()?;
+    let minute: i64 = time.next()?.parse().ok()?;
+    let second: i64 = time.next()?.parse().ok()?;
+
+    // Days since the Unix epoch using the days_from_civil algorithm (Howard Hinnant).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as i64;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days_since_epoch = era * 146097 + doe - 719468;
+
+    Some(days_since_epoch * 86400 + hour * 3600 + minute * 60 + second)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -521,4 +1994,46 @@ mod tests {
         let expires = cookie.expires.unwrap();
         assert!(expires >= now + 3599 && expires <= now + 3601);
     }
+
+    #[test]
+    fn test_cookie_canonicalize() {
+        let cookie = Cookie::new("session", "\"abc123\"")
+            .domain("..EXAMPLE.com")
+            .same_site(SameSite::Strict);
+
+        let canonical = cookie.canonicalize("https://example.com/app/page").unwrap();
+
+        assert_eq!(canonical.value, "abc123");
+        assert_eq!(canonical.domain, Some(".example.com".to_string()));
+        assert_eq!(canonical.path, Some("/app".to_string()));
+    }
+
+    #[test]
+    fn test_select_for_eviction_prefers_low_priority_and_lru() {
+        let records = vec![
+            (Cookie::new("low_old", "v").priority(Priority::Low), 1),
+            (Cookie::new("low_new", "v").priority(Priority::Low), 3),
+            (Cookie::new("high", "v").priority(Priority::High), 2),
+        ];
+        let candidates = vec![0, 1, 2];
+        let mut evicted = vec![false; records.len()];
+
+        // max=2 triggers eviction down to target=2, with no high-priority
+        // protection, so only the least-recently-accessed low-priority
+        // cookie (index 0) should be evicted.
+        select_for_eviction(&records, &candidates, 2, 2, 0, &mut evicted);
+
+        assert_eq!(evicted, vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_select_for_eviction_under_max_is_a_noop() {
+        let records = vec![(Cookie::new("a", "v"), 1), (Cookie::new("b", "v"), 2)];
+        let candidates = vec![0, 1];
+        let mut evicted = vec![false; records.len()];
+
+        select_for_eviction(&records, &candidates, 5, 1, 0, &mut evicted);
+
+        assert_eq!(evicted, vec![false, false]);
+    }
 }
\ No newline at end of file
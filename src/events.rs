@@ -0,0 +1,477 @@
+//! Input event types used to drive a `WebView` in windowless rendering mode.
+//!
+//! In windowed mode the native window handles mouse, keyboard, and IME input
+//! itself, so these types are only consumed by the
+//! `WindowlessRenderWebView` input methods on `WebView`.
+
+use bitflags::bitflags;
+
+/// A mouse button.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum MouseButton {
+    Left,
+    Middle,
+    Right,
+}
+
+/// A point in view coordinates.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A windowless-mode mouse event, consumed by `WebView::mouse`.
+#[derive(Debug, Copy, Clone)]
+pub enum MouseEvent {
+    /// The pointer moved to `Position`.
+    Move(Position),
+    /// The wheel was scrolled by `Position`, treated as a raw delta.
+    Wheel(Position),
+    /// `button` was pressed (`true`) or released (`false`), optionally
+    /// updating the pointer's `Position` first.
+    Click(MouseButton, bool, Option<Position>),
+}
+
+bitflags! {
+    /// Modifier keys held during a keyboard or mouse event.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct KeyboardModifiers: u32 {
+        const None = 0;
+        const Win = 1 << 0;
+        const Shift = 1 << 1;
+        const Ctrl = 1 << 2;
+        const Alt = 1 << 3;
+        const Command = 1 << 4;
+        const CapsLock = 1 << 5;
+    }
+}
+
+/// The kind of a keyboard event.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum KeyboardEventType {
+    KeyDown,
+    KeyUp,
+    Char,
+}
+
+/// A layout-independent physical key position, identified by its location
+/// on the keyboard rather than the character it produces under the
+/// current layout. Named after winit's `KeyCode`, and intended to be
+/// filled in directly from a winit `PhysicalKey::Code` when forwarding
+/// input from a winit event loop.
+///
+/// Use [`PhysicalKey::native_key_code`] to translate into the
+/// platform-native code `KeyboardEvent::native_key_code` expects.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum PhysicalKey {
+    KeyA,
+    KeyB,
+    KeyC,
+    KeyD,
+    KeyE,
+    KeyF,
+    KeyG,
+    KeyH,
+    KeyI,
+    KeyJ,
+    KeyK,
+    KeyL,
+    KeyM,
+    KeyN,
+    KeyO,
+    KeyP,
+    KeyQ,
+    KeyR,
+    KeyS,
+    KeyT,
+    KeyU,
+    KeyV,
+    KeyW,
+    KeyX,
+    KeyY,
+    KeyZ,
+    Digit0,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+    Escape,
+    Tab,
+    CapsLock,
+    ShiftLeft,
+    ShiftRight,
+    ControlLeft,
+    ControlRight,
+    AltLeft,
+    AltRight,
+    SuperLeft,
+    SuperRight,
+    Space,
+    Enter,
+    Backspace,
+    Insert,
+    Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    ArrowUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+}
+
+impl PhysicalKey {
+    /// Translate this layout-independent key position into the
+    /// platform-native scancode/virtual-key `KeyboardEvent::native_key_code`
+    /// expects, so callers forwarding events from winit don't have to
+    /// maintain their own per-platform keycode table.
+    ///
+    /// Windows uses the Win32 virtual-key code, macOS the `kVK_*` keyboard
+    /// scancode, and Linux/X11 the evdev scancode offset by 8 (X11 keycodes
+    /// reserve the first 8 for internal use).
+    pub fn native_key_code(self) -> i32 {
+        #[cfg(target_os = "windows")]
+        {
+            match self {
+                Self::KeyA => 0x41,
+                Self::KeyB => 0x42,
+                Self::KeyC => 0x43,
+                Self::KeyD => 0x44,
+                Self::KeyE => 0x45,
+                Self::KeyF => 0x46,
+                Self::KeyG => 0x47,
+                Self::KeyH => 0x48,
+                Self::KeyI => 0x49,
+                Self::KeyJ => 0x4A,
+                Self::KeyK => 0x4B,
+                Self::KeyL => 0x4C,
+                Self::KeyM => 0x4D,
+                Self::KeyN => 0x4E,
+                Self::KeyO => 0x4F,
+                Self::KeyP => 0x50,
+                Self::KeyQ => 0x51,
+                Self::KeyR => 0x52,
+                Self::KeyS => 0x53,
+                Self::KeyT => 0x54,
+                Self::KeyU => 0x55,
+                Self::KeyV => 0x56,
+                Self::KeyW => 0x57,
+                Self::KeyX => 0x58,
+                Self::KeyY => 0x59,
+                Self::KeyZ => 0x5A,
+                Self::Digit0 => 0x30,
+                Self::Digit1 => 0x31,
+                Self::Digit2 => 0x32,
+                Self::Digit3 => 0x33,
+                Self::Digit4 => 0x34,
+                Self::Digit5 => 0x35,
+                Self::Digit6 => 0x36,
+                Self::Digit7 => 0x37,
+                Self::Digit8 => 0x38,
+                Self::Digit9 => 0x39,
+                Self::Escape => 0x1B,
+                Self::Tab => 0x09,
+                Self::CapsLock => 0x14,
+                Self::ShiftLeft | Self::ShiftRight => 0x10,
+                Self::ControlLeft | Self::ControlRight => 0x11,
+                Self::AltLeft | Self::AltRight => 0x12,
+                Self::SuperLeft | Self::SuperRight => 0x5B,
+                Self::Space => 0x20,
+                Self::Enter => 0x0D,
+                Self::Backspace => 0x08,
+                Self::Insert => 0x2D,
+                Self::Delete => 0x2E,
+                Self::Home => 0x24,
+                Self::End => 0x23,
+                Self::PageUp => 0x21,
+                Self::PageDown => 0x22,
+                Self::ArrowUp => 0x26,
+                Self::ArrowDown => 0x28,
+                Self::ArrowLeft => 0x25,
+                Self::ArrowRight => 0x27,
+                Self::F1 => 0x70,
+                Self::F2 => 0x71,
+                Self::F3 => 0x72,
+                Self::F4 => 0x73,
+                Self::F5 => 0x74,
+                Self::F6 => 0x75,
+                Self::F7 => 0x76,
+                Self::F8 => 0x77,
+                Self::F9 => 0x78,
+                Self::F10 => 0x79,
+                Self::F11 => 0x7A,
+                Self::F12 => 0x7B,
+            }
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            match self {
+                Self::KeyA => 0x00,
+                Self::KeyB => 0x0B,
+                Self::KeyC => 0x08,
+                Self::KeyD => 0x02,
+                Self::KeyE => 0x0E,
+                Self::KeyF => 0x03,
+                Self::KeyG => 0x05,
+                Self::KeyH => 0x04,
+                Self::KeyI => 0x22,
+                Self::KeyJ => 0x26,
+                Self::KeyK => 0x28,
+                Self::KeyL => 0x25,
+                Self::KeyM => 0x2E,
+                Self::KeyN => 0x2D,
+                Self::KeyO => 0x1F,
+                Self::KeyP => 0x23,
+                Self::KeyQ => 0x0C,
+                Self::KeyR => 0x0F,
+                Self::KeyS => 0x01,
+                Self::KeyT => 0x11,
+                Self::KeyU => 0x20,
+                Self::KeyV => 0x09,
+                Self::KeyW => 0x0D,
+                Self::KeyX => 0x07,
+                Self::KeyY => 0x10,
+                Self::KeyZ => 0x06,
+                Self::Digit0 => 0x1D,
+                Self::Digit1 => 0x12,
+                Self::Digit2 => 0x13,
+                Self::Digit3 => 0x14,
+                Self::Digit4 => 0x15,
+                Self::Digit5 => 0x17,
+                Self::Digit6 => 0x16,
+                Self::Digit7 => 0x1A,
+                Self::Digit8 => 0x1C,
+                Self::Digit9 => 0x19,
+                Self::Escape => 0x35,
+                Self::Tab => 0x30,
+                Self::CapsLock => 0x39,
+                Self::ShiftLeft => 0x38,
+                Self::ShiftRight => 0x3C,
+                Self::ControlLeft => 0x3B,
+                Self::ControlRight => 0x3E,
+                Self::AltLeft => 0x3A,
+                Self::AltRight => 0x3D,
+                Self::SuperLeft => 0x37,
+                Self::SuperRight => 0x36,
+                Self::Space => 0x31,
+                Self::Enter => 0x24,
+                Self::Backspace => 0x33,
+                Self::Insert => 0x72,
+                Self::Delete => 0x75,
+                Self::Home => 0x73,
+                Self::End => 0x77,
+                Self::PageUp => 0x74,
+                Self::PageDown => 0x79,
+                Self::ArrowUp => 0x7E,
+                Self::ArrowDown => 0x7D,
+                Self::ArrowLeft => 0x7B,
+                Self::ArrowRight => 0x7C,
+                Self::F1 => 0x7A,
+                Self::F2 => 0x78,
+                Self::F3 => 0x63,
+                Self::F4 => 0x76,
+                Self::F5 => 0x60,
+                Self::F6 => 0x61,
+                Self::F7 => 0x62,
+                Self::F8 => 0x64,
+                Self::F9 => 0x65,
+                Self::F10 => 0x6D,
+                Self::F11 => 0x67,
+                Self::F12 => 0x6F,
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            // evdev scancode, offset by 8 to match the X11/XKB keycode CEF
+            // expects on Linux.
+            const X11_OFFSET: i32 = 8;
+
+            let evdev = match self {
+                Self::Escape => 1,
+                Self::Digit1 => 2,
+                Self::Digit2 => 3,
+                Self::Digit3 => 4,
+                Self::Digit4 => 5,
+                Self::Digit5 => 6,
+                Self::Digit6 => 7,
+                Self::Digit7 => 8,
+                Self::Digit8 => 9,
+                Self::Digit9 => 10,
+                Self::Digit0 => 11,
+                Self::Backspace => 14,
+                Self::Tab => 15,
+                Self::KeyQ => 16,
+                Self::KeyW => 17,
+                Self::KeyE => 18,
+                Self::KeyR => 19,
+                Self::KeyT => 20,
+                Self::KeyY => 21,
+                Self::KeyU => 22,
+                Self::KeyI => 23,
+                Self::KeyO => 24,
+                Self::KeyP => 25,
+                Self::Enter => 28,
+                Self::ControlLeft => 29,
+                Self::KeyA => 30,
+                Self::KeyS => 31,
+                Self::KeyD => 32,
+                Self::KeyF => 33,
+                Self::KeyG => 34,
+                Self::KeyH => 35,
+                Self::KeyJ => 36,
+                Self::KeyK => 37,
+                Self::KeyL => 38,
+                Self::ShiftLeft => 42,
+                Self::KeyZ => 44,
+                Self::KeyX => 45,
+                Self::KeyC => 46,
+                Self::KeyV => 47,
+                Self::KeyB => 48,
+                Self::KeyN => 49,
+                Self::KeyM => 50,
+                Self::ShiftRight => 54,
+                Self::AltLeft => 56,
+                Self::Space => 57,
+                Self::CapsLock => 58,
+                Self::F1 => 59,
+                Self::F2 => 60,
+                Self::F3 => 61,
+                Self::F4 => 62,
+                Self::F5 => 63,
+                Self::F6 => 64,
+                Self::F7 => 65,
+                Self::F8 => 66,
+                Self::F9 => 67,
+                Self::F10 => 68,
+                Self::F11 => 87,
+                Self::F12 => 88,
+                Self::ControlRight => 97,
+                Self::AltRight => 100,
+                Self::Home => 102,
+                Self::ArrowUp => 103,
+                Self::PageUp => 104,
+                Self::ArrowLeft => 105,
+                Self::ArrowRight => 106,
+                Self::End => 107,
+                Self::ArrowDown => 108,
+                Self::PageDown => 109,
+                Self::Insert => 110,
+                Self::Delete => 111,
+                Self::SuperLeft => 125,
+                Self::SuperRight => 126,
+            };
+
+            evdev + X11_OFFSET
+        }
+    }
+}
+
+/// A windowless-mode keyboard event, consumed by `WebView::keyboard`.
+#[derive(Debug, Copy, Clone)]
+pub struct KeyboardEvent {
+    pub ty: KeyboardEventType,
+    pub modifiers: KeyboardModifiers,
+    /// The character generated by the key event, already translated
+    /// according to the current keyboard layout and modifiers.
+    pub character: u16,
+    /// The character generated by the key event, ignoring modifiers other
+    /// than shift (e.g. the base key regardless of ctrl/alt).
+    pub unmodified_character: u16,
+    pub windows_key_code: i32,
+    pub native_key_code: i32,
+    pub is_system_key: bool,
+    /// Whether focus is currently on an editable field, letting CEF route
+    /// the key event to the page's text input handling.
+    pub focus_on_editable_field: bool,
+    /// Whether this is an auto-repeated `KeyDown` generated by the key
+    /// being held, rather than a fresh press.
+    pub is_repeat: bool,
+}
+
+/// Describes a mouse button press or release, consumed by
+/// `WebView::mouse_button`.
+#[derive(Debug, Copy, Clone)]
+pub struct MouseButtonEvent {
+    pub pressed: bool,
+    /// The number of consecutive clicks, used by the page to distinguish
+    /// single/double/triple clicks.
+    pub click_count: u32,
+}
+
+/// A scroll delta, consumed by `WebView::mouse_wheel`.
+///
+/// Mirrors winit's distinction between line-based scrolling (a physical
+/// mouse wheel, reported in "lines") and pixel-based scrolling (a
+/// trackpad, reported in device pixels), so host apps can forward either
+/// kind of input faithfully.
+#[derive(Debug, Copy, Clone)]
+pub enum ScrollDelta {
+    LineDelta(f32, f32),
+    PixelDelta { x: f64, y: f64 },
+}
+
+/// The visual style of an IME composition underline segment, matching
+/// `cef_composition_underline_style_t`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum UnderlineStyle {
+    Solid,
+    Dot,
+    Dash,
+    None,
+}
+
+/// A single styled underline segment within an in-progress IME
+/// composition, matching `cef_composition_underline_t`.
+#[derive(Debug, Copy, Clone)]
+pub struct CompositionUnderline {
+    /// The character range, relative to the composition text, covered by
+    /// this underline.
+    pub range: (u32, u32),
+    /// ARGB underline color.
+    pub color: u32,
+    /// ARGB background color.
+    pub background_color: u32,
+    pub thick: bool,
+    pub style: UnderlineStyle,
+}
+
+/// A windowless-mode IME action, consumed by `WebView::ime`.
+#[derive(Debug, Clone)]
+pub enum IMEAction {
+    /// Update the in-progress composition text.
+    SetComposition {
+        text: String,
+        underlines: Vec<CompositionUnderline>,
+        /// The caret/selection within `text`, in characters.
+        selection_range: (u32, u32),
+        /// The range of the document being replaced by this composition,
+        /// in characters.
+        replacement_range: (u32, u32),
+    },
+    /// Finalize `text` as committed input and clear any in-progress
+    /// composition.
+    Commit(String),
+    /// Abort any in-progress composition without committing it.
+    Cancel,
+}
@@ -55,7 +55,7 @@
 //!
 //! fn main() {
 //!     if wew::is_subprocess() {
-//!         wew::execute_subprocess();
+//!         wew::execute_subprocess(|_kind| {});
 //!
 //!         return;
 //!     }
@@ -108,6 +108,7 @@
     doc(cfg_hide(doc, docsrs))
 )]
 
+pub mod cookie;
 pub mod events;
 pub mod request;
 pub mod runtime;
@@ -116,7 +117,7 @@ pub mod webview;
 
 use std::sync::atomic::Ordering;
 
-use self::runtime::{RUNTIME_RUNNING, RuntimeAttributesBuilder};
+use self::runtime::{PUMP_DEADLINE_MS, RUNTIME_RUNNING, RuntimeAttributesBuilder};
 
 #[cfg(feature = "winit")]
 pub use winit;
@@ -146,6 +147,12 @@ pub enum Error {
     /// will trigger this error.
     RuntimeNotInitialization,
     FailedToCreateWebView,
+    /// `WebView::evaluate_script` raised a JavaScript exception; the string
+    /// is the exception's `String(e)` representation.
+    ScriptEvaluationFailed(String),
+    /// `with_remote_debugging_port` was given port `0`, which can't be
+    /// turned into a predictable `devtools_endpoint`.
+    InvalidRemoteDebuggingPort,
 }
 
 impl std::error::Error for Error {}
@@ -257,6 +264,8 @@ impl MessagePumpLoop {
         if RUNTIME_RUNNING.load(Ordering::Relaxed) {
             unsafe { sys::poll_message_loop() }
         }
+
+        PUMP_DEADLINE_MS.store(u64::MAX, Ordering::Relaxed);
     }
 }
 
@@ -286,16 +295,65 @@ pub struct NativeWindowWebView;
 
 impl WebViewAbstract for NativeWindowWebView {}
 
+/// The CEF/Chromium helper-process role, parsed from the `--type=` argument.
+///
+/// CEF runs one process per role, and on macOS each role should ship inside
+/// its own signed/notarized helper `.app` bundle rather than one catch-all
+/// executable, mirroring the split Electron's `mac_helpers.gni` performs
+/// (`Renderer`, `GPU`, `Plugin`, `Alloy`). The browser process has no
+/// `--type=` argument at all, so [`subprocess_type`] returns `None` there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubprocessType {
+    /// `--type=renderer`
+    Renderer,
+    /// `--type=gpu-process`
+    Gpu,
+    /// `--type=utility`
+    Utility,
+    /// `--type=ppapi`, a legacy PPAPI plugin process
+    Plugin,
+    /// Any other `--type=` value this crate doesn't name above.
+    Other(String),
+}
+
+impl SubprocessType {
+    fn parse(value: &str) -> Self {
+        match value {
+            "renderer" => Self::Renderer,
+            "gpu-process" => Self::Gpu,
+            "utility" => Self::Utility,
+            "ppapi" => Self::Plugin,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// The current process's [`SubprocessType`], or `None` if this is the
+/// browser process (no `--type=` argument).
+pub fn subprocess_type() -> Option<SubprocessType> {
+    std::env::args().find_map(|it| {
+        it.strip_prefix("--type=")
+            .map(|value| SubprocessType::parse(value))
+    })
+}
+
 /// Execute subprocess
 ///
 /// This method is used to start a subprocess in a separate process.
+/// `handler` is called with this process's [`SubprocessType`] before control
+/// is handed to CEF, so embedders can customize behavior per role, e.g.
+/// registering scheme handlers only in renderer processes.
 ///
 /// ## Examples
 ///
 /// ```no_run
 /// fn main() {
 ///     if wew::is_subprocess() {
-///         wew::execute_subprocess();
+///         wew::execute_subprocess(|kind| {
+///             if kind == Some(wew::SubprocessType::Renderer) {
+///                 // Renderer-only setup goes here.
+///             }
+///         });
 ///
 ///         return;
 ///     }
@@ -306,11 +364,13 @@ impl WebViewAbstract for NativeWindowWebView {}
 ///
 /// Do not call this function in an asynchronous runtime, such as tokio,
 /// which can lead to unexpected crashes!
-pub fn execute_subprocess() -> bool {
+pub fn execute_subprocess(handler: impl FnOnce(Option<SubprocessType>)) -> bool {
     if !utils::is_main_thread() {
         panic!("this operation is not allowed in non-main threads!");
     }
 
+    handler(subprocess_type());
+
     let args = utils::Args::default();
     (unsafe { sys::execute_subprocess(args.size() as _, args.as_ptr() as _) }) == 0
 }
@@ -1,57 +1,308 @@
 use std::{
     ffi::{CStr, CString, c_void},
     fs::File,
-    io::{Read, Seek, SeekFrom},
+    io::{Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
     ptr::null_mut,
     sync::Arc,
+    time::UNIX_EPOCH,
 };
 
+use brotli::CompressorWriter;
+use flate2::{Compression, write::GzEncoder};
+use parking_lot::Mutex;
 use url::Url;
 
 use crate::{sys, utils::ThreadSafePointer};
 
+/// A parsed single-range `Range: bytes=start-end` request header. Suffix
+/// ranges (`bytes=-500`) and multi-range requests aren't supported, matching
+/// the single-range case `<video>`/`<audio>` elements actually issue.
+#[derive(Debug, Clone, Copy)]
+enum ByteRange {
+    /// `bytes=start-end`, end inclusive.
+    Closed { start: u64, end: u64 },
+    /// `bytes=start-`, open-ended, runs to the end of the file.
+    Open { start: u64 },
+}
+
+fn parse_byte_range(value: &str) -> Option<ByteRange> {
+    let (start, end) = value.strip_prefix("bytes=")?.split_once('-')?;
+
+    if start.is_empty() {
+        return None;
+    }
+
+    let start: u64 = start.parse().ok()?;
+
+    if end.is_empty() {
+        Some(ByteRange::Open { start })
+    } else {
+        Some(ByteRange::Closed {
+            start,
+            end: end.parse().ok()?,
+        })
+    }
+}
+
+const HTTP_DATE_DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const HTTP_DATE_MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Days since the epoch to `(year, month, day)`, using Howard Hinnant's
+/// `civil_from_days` algorithm. Avoids pulling in a date/time crate just to
+/// render an RFC 7231 `Last-Modified` header.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+/// The inverse of [`civil_from_days`]: `(year, month, day)` to days since
+/// the epoch.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u64;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy as u64;
+
+    era * 146097 + doe as i64 - 719468
+}
+
+/// Render a Unix timestamp as an RFC 7231 IMF-fixdate, e.g.
+/// `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn format_http_date(unix_secs: u64) -> String {
+    let days = (unix_secs / 86400) as i64;
+    let secs_of_day = unix_secs % 86400;
+    let (year, month, day) = civil_from_days(days);
+    let weekday = HTTP_DATE_DAY_NAMES[((days.rem_euclid(7) + 4) % 7) as usize];
+    let month_name = HTTP_DATE_MONTH_NAMES[(month - 1) as usize];
+
+    format!(
+        "{weekday}, {day:02} {month_name} {year:04} {:02}:{:02}:{:02} GMT",
+        secs_of_day / 3600,
+        (secs_of_day % 3600) / 60,
+        secs_of_day % 60,
+    )
+}
+
+/// Parse an RFC 7231 IMF-fixdate, as sent in `If-Modified-Since`, into a
+/// Unix timestamp.
+fn parse_http_date(value: &str) -> Option<u64> {
+    let mut parts = value.trim().split_once(", ")?.1.split_whitespace();
+
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month_name = parts.next()?;
+    let month = HTTP_DATE_MONTH_NAMES
+        .iter()
+        .position(|name| *name == month_name)? as u32
+        + 1;
+    let year: i64 = parts.next()?.parse().ok()?;
+
+    let mut time = parts.next()?.split(':');
+    let hour: u64 = time.next()?.parse().ok()?;
+    let minute: u64 = time.next()?.parse().ok()?;
+    let second: u64 = time.next()?.parse().ok()?;
+
+    Some(days_from_civil(year, month, day) as u64 * 86400 + hour * 3600 + minute * 60 + second)
+}
+
 struct LocalDiskRequestHandler {
     file: Option<File>,
     path: PathBuf,
+    range: Option<ByteRange>,
+    if_none_match: Option<String>,
+    if_modified_since: Option<u64>,
+    /// Set by `open` once the requested range is resolved against the
+    /// file's actual length.
+    resolved_range: Option<(u64, u64)>,
+    /// Set by `open` when the requested range can't be satisfied; causes
+    /// `get_response` to report `416` and `read` to return nothing.
+    unsatisfiable: bool,
+    /// Set by `open` when a validator header matched, short-circuiting to a
+    /// bodyless `304`.
+    not_modified: bool,
+    file_len: u64,
+    etag: String,
+    last_modified: String,
+    remaining: u64,
 }
 
 impl LocalDiskRequestHandler {
-    fn new(path: PathBuf) -> Self {
-        Self { file: None, path }
+    fn new(
+        path: PathBuf,
+        range: Option<ByteRange>,
+        if_none_match: Option<String>,
+        if_modified_since: Option<u64>,
+    ) -> Self {
+        Self {
+            file: None,
+            path,
+            range,
+            if_none_match,
+            if_modified_since,
+            resolved_range: None,
+            unsatisfiable: false,
+            not_modified: false,
+            file_len: 0,
+            etag: String::new(),
+            last_modified: String::new(),
+            remaining: 0,
+        }
     }
 }
 
 impl RequestHandler for LocalDiskRequestHandler {
     fn open(&mut self) -> bool {
-        if let Ok(file) = File::open(&self.path) {
-            self.file.replace(file);
+        let Ok(metadata) = std::fs::metadata(&self.path) else {
+            return false;
+        };
 
-            true
-        } else {
-            false
+        self.file_len = metadata.len();
+
+        let mtime_secs = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        self.etag = format!("W/\"{}-{mtime_secs}\"", self.file_len);
+        self.last_modified = format_http_date(mtime_secs);
+
+        // `If-None-Match` takes precedence over `If-Modified-Since` when both
+        // are present.
+        let cached = match &self.if_none_match {
+            Some(value) => value == &self.etag,
+            None => self
+                .if_modified_since
+                .is_some_and(|since| since >= mtime_secs),
+        };
+
+        if cached {
+            self.not_modified = true;
+
+            return true;
+        }
+
+        let Ok(mut file) = File::open(&self.path) else {
+            return false;
+        };
+
+        if let Some(range) = self.range {
+            let (start, end) = match range {
+                ByteRange::Closed { start, end } => {
+                    (start, end.min(self.file_len.saturating_sub(1)))
+                }
+                ByteRange::Open { start } => (start, self.file_len.saturating_sub(1)),
+            };
+
+            if self.file_len == 0 || start > end || start >= self.file_len {
+                self.unsatisfiable = true;
+            } else {
+                self.resolved_range = Some((start, end));
+
+                if file.seek(SeekFrom::Start(start)).is_err() {
+                    return false;
+                }
+            }
         }
+
+        self.remaining = match self.resolved_range {
+            Some((start, end)) => end - start + 1,
+            None => self.file_len,
+        };
+
+        self.file.replace(file);
+
+        true
     }
 
     fn get_response(&mut self) -> Option<Response> {
+        let mime_type = get_mime_type(self.path.as_path())?;
+
+        let validator_headers = vec![
+            ("ETag".to_string(), self.etag.clone()),
+            ("Last-Modified".to_string(), self.last_modified.clone()),
+        ];
+
+        if self.not_modified {
+            return Some(Response {
+                status_code: 304,
+                content_length: 0,
+                mime_type,
+                headers: validator_headers,
+            });
+        }
+
+        if self.unsatisfiable {
+            return Some(Response {
+                status_code: 416,
+                content_length: 0,
+                mime_type,
+                headers: vec![("Content-Range".to_string(), format!("bytes */{}", self.file_len))],
+            });
+        }
+
+        if let Some((start, end)) = self.resolved_range {
+            let mut headers = validator_headers;
+            headers.push((
+                "Content-Range".to_string(),
+                format!("bytes {start}-{end}/{}", self.file_len),
+            ));
+
+            return Some(Response {
+                status_code: 206,
+                content_length: end - start + 1,
+                mime_type,
+                headers,
+            });
+        }
+
+        let mut headers = validator_headers;
+        headers.push(("Accept-Ranges".to_string(), "bytes".to_string()));
+
         Some(Response {
             status_code: 200,
-            mime_type: get_mime_type(&self.path.as_path())?,
-            content_length: self.file.as_ref()?.metadata().ok()?.len(),
+            content_length: self.file_len,
+            mime_type,
+            headers,
         })
     }
 
     fn skip(&mut self, size: usize) -> Option<usize> {
-        Some(
-            self.file
-                .as_mut()?
-                .seek(SeekFrom::Start(size as u64))
-                .ok()? as usize,
-        )
+        let base = self.resolved_range.map_or(0, |(start, _)| start);
+
+        self.file
+            .as_mut()?
+            .seek(SeekFrom::Start(base + size as u64))
+            .ok()?;
+        self.remaining = self.remaining.saturating_sub(size as u64);
+
+        Some(size)
     }
 
     fn read(&mut self, buffer: &mut [u8]) -> Option<usize> {
-        Some(self.file.as_mut()?.read(buffer).ok()?)
+        if self.unsatisfiable || self.remaining == 0 {
+            return Some(0);
+        }
+
+        let limit = (buffer.len() as u64).min(self.remaining) as usize;
+        let read = self.file.as_mut()?.read(&mut buffer[..limit]).ok()?;
+        self.remaining -= read as u64;
+
+        Some(read)
     }
 
     fn cancel(&mut self) {
@@ -85,6 +336,7 @@ impl RequestHandler for LocalDiskRequestHandler {
 /// internally.
 pub struct RequestHandlerWithLocalDisk {
     root_dir: PathBuf,
+    compression_level: Option<u32>,
 }
 
 impl RequestHandlerWithLocalDisk {
@@ -96,8 +348,17 @@ impl RequestHandlerWithLocalDisk {
     pub fn new(root_dir: &str) -> Self {
         Self {
             root_dir: PathBuf::from(root_dir),
+            compression_level: None,
         }
     }
+
+    /// Opt into transparently compressing compressible responses (`gzip` or
+    /// `br`, negotiated against the request's `Accept-Encoding`) at the
+    /// given level. See [`CompressedRequestHandler`] for the exact rules.
+    pub fn with_compression(mut self, level: u32) -> Self {
+        self.compression_level = Some(level);
+        self
+    }
 }
 
 impl RequestHandlerFactory for RequestHandlerWithLocalDisk {
@@ -113,9 +374,340 @@ impl RequestHandlerFactory for RequestHandlerWithLocalDisk {
             path = path[1..].to_string();
         }
 
-        Some(Box::new(LocalDiskRequestHandler::new(
+        let range = request.header("Range").and_then(parse_byte_range);
+        let if_none_match = request.header("If-None-Match").map(str::to_string);
+        let if_modified_since = request.header("If-Modified-Since").and_then(parse_http_date);
+
+        let handler: Box<dyn RequestHandler> = Box::new(LocalDiskRequestHandler::new(
             self.root_dir.join(path),
-        )))
+            range,
+            if_none_match,
+            if_modified_since,
+        ));
+
+        Some(match self.compression_level {
+            Some(level) => Box::new(CompressedRequestHandler::new(
+                handler,
+                request.header("Accept-Encoding"),
+                level,
+            )),
+            None => handler,
+        })
+    }
+}
+
+struct EmbeddedAssetRequestHandler {
+    data: Arc<[u8]>,
+    position: usize,
+    mime_type: Option<String>,
+}
+
+impl RequestHandler for EmbeddedAssetRequestHandler {
+    fn open(&mut self) -> bool {
+        true
+    }
+
+    fn get_response(&mut self) -> Option<Response> {
+        Some(Response {
+            status_code: 200,
+            content_length: self.data.len() as u64,
+            mime_type: self.mime_type.clone().unwrap_or_else(|| "application/octet-stream".to_string()),
+            headers: Vec::new(),
+        })
+    }
+
+    fn skip(&mut self, size: usize) -> Option<usize> {
+        self.position = size.min(self.data.len());
+
+        Some(self.position)
+    }
+
+    fn read(&mut self, buffer: &mut [u8]) -> Option<usize> {
+        let remaining = &self.data[self.position..];
+        let len = remaining.len().min(buffer.len());
+
+        buffer[..len].copy_from_slice(&remaining[..len]);
+        self.position += len;
+
+        Some(len)
+    }
+
+    fn cancel(&mut self) {}
+}
+
+/// This request handler is used to serve assets embedded in the executable
+/// at compile time (e.g. via `include_bytes!` or a build script), so a
+/// single-binary distribution doesn't need a front-end directory alongside
+/// it at runtime.
+///
+/// ## Example
+///
+/// ```no_run
+/// use wew::request::RequestHandlerWithEmbeddedAssets;
+///
+/// let handler = RequestHandlerWithEmbeddedAssets::new([
+///     ("index.html", include_bytes!("../assets/index.html").as_slice()),
+///     ("index.css", include_bytes!("../assets/index.css").as_slice()),
+/// ]);
+/// ```
+///
+/// A request whose path has no matching asset and no file extension (e.g.
+/// `/settings/profile`, as issued by a client-side router) falls back to
+/// `index.html` when `spa_fallback` is enabled, which it is by default.
+pub struct RequestHandlerWithEmbeddedAssets {
+    assets: std::collections::HashMap<String, Arc<[u8]>>,
+    spa_fallback: bool,
+    compression_level: Option<u32>,
+}
+
+impl RequestHandlerWithEmbeddedAssets {
+    /// Create a request handler from an `IntoIterator` of `path -> bytes`
+    /// pairs. Paths are matched without a leading `/`, e.g. `"index.html"`
+    /// or `"images/a.jpg"`.
+    pub fn new<I, P, B>(assets: I) -> Self
+    where
+        I: IntoIterator<Item = (P, B)>,
+        P: Into<String>,
+        B: Into<Arc<[u8]>>,
+    {
+        Self {
+            assets: assets
+                .into_iter()
+                .map(|(path, data)| (path.into(), data.into()))
+                .collect(),
+            spa_fallback: true,
+            compression_level: None,
+        }
+    }
+
+    /// Control whether a path with no matching asset and no file extension
+    /// falls back to `index.html` instead of producing a `404`. Enabled by
+    /// default.
+    pub fn with_spa_fallback(mut self, enabled: bool) -> Self {
+        self.spa_fallback = enabled;
+        self
+    }
+
+    /// Opt into transparently compressing compressible responses (`gzip` or
+    /// `br`, negotiated against the request's `Accept-Encoding`) at the
+    /// given level. See [`CompressedRequestHandler`] for the exact rules.
+    pub fn with_compression(mut self, level: u32) -> Self {
+        self.compression_level = Some(level);
+        self
+    }
+}
+
+impl RequestHandlerFactory for RequestHandlerWithEmbeddedAssets {
+    fn request(&self, request: &Request) -> Option<Box<dyn RequestHandler>> {
+        let url = if request.url.is_empty() {
+            "http://localhost/index.html"
+        } else {
+            request.url
+        };
+
+        let mut path = Url::parse(url).ok()?.path().to_string();
+        if path.starts_with('/') {
+            path = path[1..].to_string();
+        }
+        if path.is_empty() {
+            path = "index.html".to_string();
+        }
+
+        let (data, mime_type) = if let Some(data) = self.assets.get(&path) {
+            (data.clone(), get_mime_type(Path::new(&path)))
+        } else if self.spa_fallback && !path.contains('.') {
+            let data = self.assets.get("index.html")?;
+
+            (data.clone(), Some("text/html".to_string()))
+        } else {
+            return None;
+        };
+
+        let handler: Box<dyn RequestHandler> = Box::new(EmbeddedAssetRequestHandler {
+            data,
+            position: 0,
+            mime_type,
+        });
+
+        Some(match self.compression_level {
+            Some(level) => Box::new(CompressedRequestHandler::new(
+                handler,
+                request.header("Accept-Encoding"),
+                level,
+            )),
+            None => handler,
+        })
+    }
+}
+
+/// Content-coding [`CompressedRequestHandler`] can produce, in preference
+/// order when a request's `Accept-Encoding` offers more than one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContentEncoding {
+    Brotli,
+    Gzip,
+}
+
+impl ContentEncoding {
+    fn header_value(self) -> &'static str {
+        match self {
+            Self::Brotli => "br",
+            Self::Gzip => "gzip",
+        }
+    }
+}
+
+fn negotiate_content_encoding(accept_encoding: Option<&str>) -> Option<ContentEncoding> {
+    let accept_encoding = accept_encoding?;
+
+    let offers = |name: &str| {
+        accept_encoding
+            .split(',')
+            .map(|token| token.split(';').next().unwrap_or("").trim())
+            .any(|token| token.eq_ignore_ascii_case(name))
+    };
+
+    if offers("br") {
+        Some(ContentEncoding::Brotli)
+    } else if offers("gzip") {
+        Some(ContentEncoding::Gzip)
+    } else {
+        None
+    }
+}
+
+/// MIME types worth compressing. Images/audio/video formats are already
+/// entropy-coded, so a second compression pass gains little and can even
+/// grow the payload.
+fn is_compressible_mime_type(mime_type: &str) -> bool {
+    mime_type.starts_with("text/")
+        || mime_type == "application/javascript"
+        || mime_type == "application/json"
+        || mime_type == "image/svg+xml"
+}
+
+/// Wraps any [`RequestHandler`] to transparently compress its response body
+/// with `gzip` or `br`, when the wrapped request's `Accept-Encoding` offers
+/// one of them and the resolved MIME type is worth compressing. `206`
+/// partial-content responses and non-compressible MIME types are passed
+/// through unchanged, since the handler can't compress a response whose
+/// `Content-Range` byte offsets were computed against the uncompressed
+/// body.
+pub struct CompressedRequestHandler {
+    inner: Box<dyn RequestHandler>,
+    encoding: Option<ContentEncoding>,
+    level: u32,
+    /// Set by `get_response` once it has decided to compress; `read` and
+    /// `skip` then serve from this buffer instead of `inner`.
+    compressed: Option<Vec<u8>>,
+    position: usize,
+}
+
+impl CompressedRequestHandler {
+    /// Wrap `inner`. `accept_encoding` is the request's `Accept-Encoding`
+    /// header value, if any; `level` is the compression level (0-9 for
+    /// `gzip`, used as the quality for `br`).
+    pub fn new(inner: Box<dyn RequestHandler>, accept_encoding: Option<&str>, level: u32) -> Self {
+        Self {
+            inner,
+            encoding: negotiate_content_encoding(accept_encoding),
+            level,
+            compressed: None,
+            position: 0,
+        }
+    }
+
+    fn compress(&self, encoding: ContentEncoding, body: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+
+        match encoding {
+            ContentEncoding::Gzip => {
+                let mut encoder = GzEncoder::new(&mut out, Compression::new(self.level));
+                encoder.write_all(body).expect("in-memory write cannot fail");
+                encoder.finish().expect("in-memory write cannot fail");
+            }
+            ContentEncoding::Brotli => {
+                let mut writer = CompressorWriter::new(&mut out, 4096, self.level, 22);
+                writer.write_all(body).expect("in-memory write cannot fail");
+            }
+        }
+
+        out
+    }
+}
+
+impl RequestHandler for CompressedRequestHandler {
+    fn open(&mut self) -> bool {
+        self.inner.open()
+    }
+
+    fn get_response(&mut self) -> Option<Response> {
+        let mut response = self.inner.get_response()?;
+
+        let Some(encoding) = self.encoding else {
+            return Some(response);
+        };
+
+        if response.status_code != 200 || !is_compressible_mime_type(&response.mime_type) {
+            return Some(response);
+        }
+
+        let mut body = Vec::with_capacity(response.content_length as usize);
+        let mut chunk = [0u8; 64 * 1024];
+
+        loop {
+            match self.inner.read(&mut chunk) {
+                Some(0) | None => break,
+                Some(read) => body.extend_from_slice(&chunk[..read]),
+            }
+        }
+
+        let compressed = self.compress(encoding, &body);
+
+        response.content_length = compressed.len() as u64;
+        response.headers.push((
+            "Content-Encoding".to_string(),
+            encoding.header_value().to_string(),
+        ));
+
+        // `Accept-Ranges` describes the representation actually returned
+        // (RFC 7233 §2.3); the wrapped handler's byte offsets apply to the
+        // uncompressed body, not this one, so don't forward it.
+        response
+            .headers
+            .retain(|(name, _)| !name.eq_ignore_ascii_case("Accept-Ranges"));
+
+        self.compressed = Some(compressed);
+
+        Some(response)
+    }
+
+    fn skip(&mut self, size: usize) -> Option<usize> {
+        if let Some(compressed) = &self.compressed {
+            self.position = size.min(compressed.len());
+
+            Some(self.position)
+        } else {
+            self.inner.skip(size)
+        }
+    }
+
+    fn read(&mut self, buffer: &mut [u8]) -> Option<usize> {
+        if let Some(compressed) = &self.compressed {
+            let remaining = &compressed[self.position..];
+            let len = remaining.len().min(buffer.len());
+
+            buffer[..len].copy_from_slice(&remaining[..len]);
+            self.position += len;
+
+            Some(len)
+        } else {
+            self.inner.read(buffer)
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.inner.cancel();
     }
 }
 
@@ -128,23 +720,49 @@ pub struct Request<'a> {
     pub method: &'a str,
     /// Request referrer
     pub referrer: &'a str,
+    /// Request headers, in the order CEF delivered them. Header names are
+    /// not deduplicated or case-normalized; use [`Request::header`] for a
+    /// case-insensitive single-value lookup.
+    pub headers: Vec<(&'a str, &'a str)>,
 }
 
 impl<'a> Request<'a> {
+    /// Look up the first header matching `name`, case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&'a str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| *v)
+    }
+
     fn from_raw_ptr(request: *mut sys::Request) -> Option<Self> {
         let request = unsafe { &*request };
 
+        let headers = if request.headers.is_null() || request.headers_len == 0 {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(request.headers, request.headers_len) }
+                .iter()
+                .filter_map(|header| {
+                    let name = unsafe { CStr::from_ptr(header.name) }.to_str().ok()?;
+                    let value = unsafe { CStr::from_ptr(header.value) }.to_str().ok()?;
+
+                    Some((name, value))
+                })
+                .collect()
+        };
+
         Some(Self {
             url: unsafe { CStr::from_ptr(request.url).to_str().ok()? },
             method: unsafe { CStr::from_ptr(request.method).to_str().ok()? },
             referrer: unsafe { CStr::from_ptr(request.referrer).to_str().ok()? },
+            headers,
         })
     }
 }
 
 /// Response information
-#[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Response {
     /// Response status code
     pub status_code: u32,
@@ -152,6 +770,9 @@ pub struct Response {
     pub content_length: u64,
     /// Response MIME type
     pub mime_type: String,
+    /// Additional response headers to send back, e.g. `Cache-Control`,
+    /// `ETag`, `Set-Cookie`, `Content-Range`.
+    pub headers: Vec<(String, String)>,
 }
 
 /// Request handler
@@ -269,8 +890,129 @@ impl<'a> CustomSchemeAttributes {
     }
 }
 
+/// Origin patterns permitted to reach the custom-scheme / native request
+/// handler, configured via
+/// `RuntimeAttributesBuilder::with_request_origin_allowlist`. Global because
+/// only one runtime may exist per process.
+static REQUEST_ORIGIN_ALLOWLIST: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+pub(crate) fn set_request_origin_allowlist(allowlist: Vec<String>) {
+    *REQUEST_ORIGIN_ALLOWLIST.lock() = allowlist;
+}
+
+/// The default port for schemes `Url::port_or_known_default` doesn't already
+/// know about (`ws`/`wss`), so origin comparisons treat `ws://host` and
+/// `ws://host:80` as the same origin.
+fn default_port(scheme: &str) -> Option<u16> {
+    match scheme {
+        "ws" => Some(80),
+        "wss" => Some(443),
+        _ => None,
+    }
+}
+
+/// Splits a pattern's `host[:port]` authority (with no scheme) into its host
+/// and port, defaulting the port from `scheme` when unspecified. Used for
+/// the `*.host` wildcard form below, whose leading `*` `Url` can't parse.
+fn split_host_port<'a>(authority: &'a str, scheme: &str) -> (&'a str, Option<u16>) {
+    match authority.rsplit_once(':') {
+        Some((host, port)) if !port.is_empty() && port.chars().all(|c| c.is_ascii_digit()) => {
+            (host, port.parse().ok())
+        }
+        _ => (
+            authority,
+            Url::parse(&format!("{scheme}://{authority}"))
+                .ok()
+                .and_then(|url| url.port_or_known_default())
+                .or(default_port(scheme)),
+        ),
+    }
+}
+
+/// Whether `referrer`'s origin (scheme + host + port) matches `pattern`.
+///
+/// `pattern` is either a plain origin (`https://example.com`, matched by
+/// exact scheme/host/port equality) or a subdomain wildcard
+/// (`https://*.example.com`, which also allows any subdomain of
+/// `example.com`). Comparing parsed origins rather than raw strings rejects
+/// lookalike hosts a prefix check would let through, e.g. a pattern of
+/// `https://example.com` must not match a referrer of
+/// `https://example.com.evil.com`.
+fn origin_matches(referrer: &str, pattern: &str) -> bool {
+    let Ok(referrer_url) = Url::parse(referrer) else {
+        return false;
+    };
+    let Some(referrer_host) = referrer_url.host_str() else {
+        return false;
+    };
+    let referrer_port = referrer_url.port_or_known_default().or(default_port(referrer_url.scheme()));
+
+    let Some((pattern_scheme, pattern_authority)) = pattern.split_once("://") else {
+        return false;
+    };
+
+    if referrer_url.scheme() != pattern_scheme {
+        return false;
+    }
+
+    if let Some(pattern_rest) = pattern_authority.strip_prefix("*.") {
+        let (pattern_host, pattern_port) = split_host_port(pattern_rest, pattern_scheme);
+        return referrer_port == pattern_port
+            && (referrer_host == pattern_host
+                || referrer_host.ends_with(&format!(".{pattern_host}")));
+    }
+
+    let Ok(pattern_url) = Url::parse(pattern) else {
+        return false;
+    };
+
+    Some(referrer_host) == pattern_url.host_str()
+        && referrer_port == pattern_url.port_or_known_default().or(default_port(pattern_scheme))
+}
+
+/// `file://` navigations and the runtime's own custom scheme (seeded into
+/// the allowlist by `IRuntime::new`) carry no referrer, or a referrer that
+/// matches one of the configured patterns.
+fn is_origin_allowed(referrer: &str) -> bool {
+    if referrer.is_empty() || referrer.starts_with("file://") {
+        return true;
+    }
+
+    REQUEST_ORIGIN_ALLOWLIST
+        .lock()
+        .iter()
+        .any(|pattern| origin_matches(referrer, pattern))
+}
+
+/// The state reachable from `on_create_request_handler`'s `context`
+/// pointer: the user's factory plus the optional per-scheme referrer
+/// allowlist configured through
+/// [`CustomRequestHandlerFactory::with_allowed_referrer_origins`].
+struct RequestHandlerFactoryContext {
+    factory: Box<dyn RequestHandlerFactory>,
+    /// `None` means this scheme has no referrer restriction of its own
+    /// beyond the process-wide
+    /// `RuntimeAttributesBuilder::with_request_origin_allowlist`.
+    allowed_referrer_origins: Mutex<Option<Vec<String>>>,
+}
+
+/// `file://` navigations carry no referrer, and an unconfigured allowlist
+/// (`None`) imposes no extra restriction beyond the process-wide one
+/// already checked by `is_origin_allowed`.
+fn is_referrer_allowed_for_scheme(allowlist: &Option<Vec<String>>, referrer: &str) -> bool {
+    let Some(patterns) = allowlist else {
+        return true;
+    };
+
+    if referrer.is_empty() || referrer.starts_with("file://") {
+        return true;
+    }
+
+    patterns.iter().any(|pattern| origin_matches(referrer, pattern))
+}
+
 struct ICustomRequestHandlerFactory {
-    raw: ThreadSafePointer<Box<dyn RequestHandlerFactory>>,
+    raw: ThreadSafePointer<RequestHandlerFactoryContext>,
     raw_handler: ThreadSafePointer<sys::RequestHandlerFactory>,
 }
 
@@ -291,7 +1033,10 @@ impl CustomRequestHandlerFactory {
     where
         T: RequestHandlerFactory + 'static,
     {
-        let raw: *mut Box<dyn RequestHandlerFactory> = Box::into_raw(Box::new(Box::new(handler)));
+        let raw: *mut RequestHandlerFactoryContext = Box::into_raw(Box::new(RequestHandlerFactoryContext {
+            factory: Box::new(handler),
+            allowed_referrer_origins: Mutex::new(None),
+        }));
         let raw_handler = Box::into_raw(Box::new(sys::RequestHandlerFactory {
             request: Some(on_create_request_handler),
             destroy_request_handler: Some(on_destroy_request_handler),
@@ -304,6 +1049,27 @@ impl CustomRequestHandlerFactory {
         }))
     }
 
+    /// Restrict this scheme so only requests whose `referrer`'s origin
+    /// matches one of `origins` (exact scheme/host/port, or a
+    /// `scheme://*.host` subdomain wildcard), or that carry no referrer at
+    /// all or a `file://` one (e.g. a top-level
+    /// `file://` navigation) are dispatched to the factory. Requests from
+    /// any other origin - for example a remote `http(s)` page loaded in the
+    /// same webview - are rejected with no handler, instead of being
+    /// served.
+    ///
+    /// This guards against a compromised or malicious remote document
+    /// reaching privileged local resources through this scheme. It's
+    /// independent of, and in addition to,
+    /// `RuntimeAttributesBuilder::with_request_origin_allowlist`, which
+    /// applies to every scheme in the process.
+    pub fn with_allowed_referrer_origins(self, origins: &[&str]) -> Self {
+        *unsafe { &*self.0.raw.as_ptr() }.allowed_referrer_origins.lock() =
+            Some(origins.iter().map(|origin| origin.to_string()).collect());
+
+        self
+    }
+
     pub(crate) fn as_raw_handler(&self) -> &ThreadSafePointer<sys::RequestHandlerFactory> {
         &self.0.raw_handler
     }
@@ -326,10 +1092,31 @@ extern "C" fn on_create_request_handler(
         return null_mut();
     }
 
+    let context = unsafe { &*(context as *mut RequestHandlerFactoryContext) };
+
     if let Some(request) = Request::from_raw_ptr(request) {
-        if let Some(handler) =
-            unsafe { &*(context as *mut Box<dyn RequestHandlerFactory>) }.request(&request)
-        {
+        if !is_origin_allowed(request.referrer) {
+            eprintln!(
+                "wew: rejected native request handler dispatch for {:?} from disallowed origin {:?}",
+                request.url, request.referrer
+            );
+
+            return null_mut();
+        }
+
+        if !is_referrer_allowed_for_scheme(
+            &context.allowed_referrer_origins.lock(),
+            request.referrer,
+        ) {
+            eprintln!(
+                "wew: rejected native request handler dispatch for {:?} from disallowed referrer {:?}",
+                request.url, request.referrer
+            );
+
+            return null_mut();
+        }
+
+        if let Some(handler) = context.factory.request(&request) {
             return Box::into_raw(Box::new(sys::RequestHandler {
                 open: Some(on_open),
                 skip: Some(on_skip),
@@ -364,6 +1151,7 @@ extern "C" fn on_get_response(response: *mut sys::Response, context: *mut c_void
             status_code: 404,
             content_length: 0,
             mime_type: "text/plain".to_string(),
+            headers: Vec::new(),
         });
 
     {
@@ -383,6 +1171,14 @@ extern "C" fn on_get_response(response: *mut sys::Response, context: *mut c_void
 
     response.status_code = res.status_code as i32;
     response.content_length = res.content_length;
+
+    if let Some(set_header) = response.set_header {
+        for (name, value) in &res.headers {
+            if let (Ok(name), Ok(value)) = (CString::new(name.as_str()), CString::new(value.as_str())) {
+                unsafe { set_header(response.set_header_context, name.as_ptr(), value.as_ptr()) };
+            }
+        }
+    }
 }
 
 extern "C" fn on_skip(size: usize, skip_bytes: *mut i32, context: *mut c_void) -> bool {
@@ -431,3 +1227,78 @@ extern "C" fn on_cancel(context: *mut c_void) {
 extern "C" fn on_destroy(context: *mut c_void) {
     drop(unsafe { Box::from_raw(context as *mut Box<dyn RequestHandler>) });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_byte_range_closed() {
+        let range = parse_byte_range("bytes=0-499").unwrap();
+        assert!(matches!(range, ByteRange::Closed { start: 0, end: 499 }));
+    }
+
+    #[test]
+    fn test_parse_byte_range_open() {
+        let range = parse_byte_range("bytes=500-").unwrap();
+        assert!(matches!(range, ByteRange::Open { start: 500 }));
+    }
+
+    #[test]
+    fn test_parse_byte_range_rejects_malformed() {
+        assert!(parse_byte_range("bytes=-500").is_none());
+        assert!(parse_byte_range("bytes=abc-def").is_none());
+        assert!(parse_byte_range("500-600").is_none());
+    }
+
+    #[test]
+    fn test_civil_from_days_epoch() {
+        // 1970-01-01 is day 0 since the epoch.
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+    }
+
+    #[test]
+    fn test_format_http_date() {
+        // 1994-11-06 08:49:37 UTC, RFC 7231's own IMF-fixdate example.
+        assert_eq!(format_http_date(784111777), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn test_parse_http_date_round_trips_format_http_date() {
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(784111777)
+        );
+    }
+
+    #[test]
+    fn test_origin_matches_exact() {
+        assert!(origin_matches("https://example.com/page", "https://example.com"));
+        assert!(!origin_matches("http://example.com/page", "https://example.com"));
+    }
+
+    #[test]
+    fn test_origin_matches_rejects_lookalike_host() {
+        // A prefix/starts_with check would wrongly let this through.
+        assert!(!origin_matches(
+            "https://example.com.evil.com/page",
+            "https://example.com"
+        ));
+    }
+
+    #[test]
+    fn test_origin_matches_wildcard_subdomain() {
+        assert!(origin_matches(
+            "https://api.example.com/page",
+            "https://*.example.com"
+        ));
+        assert!(origin_matches(
+            "https://example.com/page",
+            "https://*.example.com"
+        ));
+        assert!(!origin_matches(
+            "https://evil.com/page",
+            "https://*.example.com"
+        ));
+    }
+}
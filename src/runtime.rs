@@ -68,15 +68,17 @@
 //! starvation.
 
 use std::{
+    collections::HashMap,
     ffi::{CString, c_void},
     marker::PhantomData,
     ops::Deref,
     ptr::null,
     sync::{
-        Arc,
-        atomic::{AtomicBool, Ordering},
+        Arc, OnceLock, mpsc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
     },
     thread,
+    time::{Duration, Instant},
 };
 
 use parking_lot::Mutex;
@@ -84,9 +86,14 @@ use parking_lot::Mutex;
 use crate::{
     Error, MainThreadMessageLoop, MessagePumpLoop, MultiThreadMessageLoop, NativeWindowWebView,
     WindowlessRenderWebView,
+    cookie::CookieManager,
+    request,
     request::{CustomSchemeAttributes, ICustomRequestHandlerFactory},
     sys,
-    utils::{AnyStringCast, Args, GetSharedRef, ThreadSafePointer, is_main_thread},
+    utils::{
+        ActivationPolicy, AnyStringCast, Args, GetSharedRef, ThreadSafePointer, is_main_thread,
+        set_activation_policy,
+    },
     webview::{
         MixWebviewHnadler, WebView, WebViewAttributes, WebViewHandler,
         WindowlessRenderWebViewHandler,
@@ -196,6 +203,32 @@ pub struct RuntimeAttributes<R, W> {
 
     /// Whether to disable signal handlers
     disable_signal_handlers: bool,
+
+    /// The macOS `NSApplication` activation policy to apply once the
+    /// runtime is created. No-op on other platforms.
+    activation_policy: Option<ActivationPolicy>,
+
+    /// The port CEF's remote-debugging / Chrome DevTools Protocol server
+    /// listens on. Once set, a CDP client (or the Chromium DevTools
+    /// frontend via `chrome://inspect`) can attach to the browser process
+    /// and all of its sub-process renderers over the wire.
+    remote_debugging_port: Option<u16>,
+
+    /// The address reported by `Runtime::devtools_endpoint` for the
+    /// remote-debugging server. CEF always binds it to `127.0.0.1`; this
+    /// only changes what host name the endpoint URL advertises, e.g. when
+    /// port-forwarding exposes it elsewhere. Defaults to `127.0.0.1`.
+    remote_debugging_address: Option<CString>,
+
+    /// How far past its requested deadline `MessagePumpLoop::poll` may run
+    /// late before `MessagePumpRuntimeHandler::on_pump_starved` fires. Only
+    /// meaningful alongside `MessagePumpLoop`; unset disables the watchdog.
+    pump_hang_threshold: Option<Duration>,
+
+    /// Origin patterns permitted to reach the custom-scheme / native request
+    /// handler. Unset defaults to only the runtime's own custom scheme and
+    /// `file://` origins.
+    request_origin_allowlist: Option<Vec<String>>,
 }
 
 impl<W> RuntimeAttributes<MainThreadMessageLoop, W> {
@@ -359,6 +392,54 @@ impl<R, W> RuntimeAttributesBuilder<R, W> {
         self.0.persist_session_cookies = value;
         self
     }
+
+    /// Set the macOS `NSApplication` activation policy to apply once the
+    /// runtime is created. No-op on other platforms.
+    pub fn with_activation_policy(mut self, value: ActivationPolicy) -> Self {
+        self.0.activation_policy = Some(value);
+        self
+    }
+
+    /// Set the port CEF's remote-debugging / Chrome DevTools Protocol
+    /// server listens on, so a CDP client (or the Chromium DevTools
+    /// frontend via `chrome://inspect`) can attach to the browser process
+    /// and all of its sub-process renderers over the wire.
+    pub fn with_remote_debugging_port(mut self, value: u16) -> Self {
+        self.0.remote_debugging_port = Some(value);
+        self
+    }
+
+    /// Set the address `Runtime::devtools_endpoint` reports for the
+    /// remote-debugging server. Only meaningful alongside
+    /// `with_remote_debugging_port`; defaults to `127.0.0.1` otherwise.
+    pub fn with_remote_debugging_address(mut self, value: &str) -> Self {
+        self.0.remote_debugging_address = Some(CString::new(value).unwrap());
+        self
+    }
+
+    /// Enable the message-pump starvation watchdog: if `MessagePumpLoop::poll`
+    /// hasn't been driven within `threshold` of its requested deadline,
+    /// `MessagePumpRuntimeHandler::on_pump_starved` fires so the integrator
+    /// can log it or force a drive. Only meaningful alongside
+    /// `MessagePumpLoop`; disabled by default.
+    pub fn with_pump_hang_threshold(mut self, threshold: Duration) -> Self {
+        self.0.pump_hang_threshold = Some(threshold);
+        self
+    }
+
+    /// Restrict which origins may reach the custom-scheme / native request
+    /// handler, following Tauri's approach of blocking remote pages from the
+    /// native IPC surface. `allowlist` entries are compared as origins
+    /// (scheme, host and port, or a `scheme://*.host` subdomain wildcard)
+    /// against the initiating frame's referrer (e.g. `"myapp://app"`).
+    /// Requests from origins outside the allowlist are rejected with a
+    /// logged, non-fatal refusal rather than reaching `RequestHandlerFactory`.
+    ///
+    /// Defaults to the runtime's own custom scheme plus `file://` origins.
+    pub fn with_request_origin_allowlist(mut self, allowlist: Vec<String>) -> Self {
+        self.0.request_origin_allowlist = Some(allowlist);
+        self
+    }
 }
 
 impl RuntimeAttributesBuilder<MultiThreadMessageLoop, NativeWindowWebView> {
@@ -436,6 +517,11 @@ pub trait RuntimeHandler: Send + Sync {
     /// running, so you need to drive the message loop as soon as possible after
     /// creating the runtime.
     fn on_context_initialized(&self) {}
+
+    /// Called when a frame arrives on a [`BroadcastChannel`] named `name`,
+    /// sent by a page in one of the runtime's `WebView`s over its message
+    /// channel. Frames sent with `BroadcastChannel::send` do not arrive here.
+    fn on_broadcast(&self, name: &str, payload: &[u8]) {}
 }
 
 /// Message pump runtime handler
@@ -450,10 +536,107 @@ pub trait MessagePumpRuntimeHandler: RuntimeHandler {
     /// The `delay` parameter indicates how long to wait before calling `poll`,
     /// the unit is milliseconds.
     fn on_schedule_message_pump_work(&self, delay: u64) {}
+
+    /// Called when the message pump has run `overdue_ms` milliseconds past
+    /// the deadline its most recent `on_schedule_message_pump_work` call
+    /// requested, without `MessagePumpLoop::poll` clearing it in between.
+    ///
+    /// Only fires when a hang threshold was configured via
+    /// `with_pump_hang_threshold`.
+    fn on_pump_starved(&self, overdue_ms: u64) {}
 }
 
 pub(crate) static RUNTIME_RUNNING: AtomicBool = AtomicBool::new(false);
 
+/// The timestamp (milliseconds since an arbitrary process-lifetime epoch,
+/// see [`monotonic_now_ms`]) by which the next `MessagePumpLoop::poll` was
+/// requested to run, per `on_schedule_message_pump_work`. `u64::MAX` means
+/// no drive is currently pending. Global because only one runtime may exist
+/// per process.
+pub(crate) static PUMP_DEADLINE_MS: AtomicU64 = AtomicU64::new(u64::MAX);
+
+fn monotonic_now_ms() -> u64 {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+    EPOCH.get_or_init(Instant::now).elapsed().as_millis() as u64
+}
+
+/// Per-runtime registry of subscriber send functions backing
+/// [`BroadcastChannel`]. Each live `WebView` registers its `send_message`
+/// closure here on creation (see `IWebView::new`) and removes it on drop, so
+/// a broadcast reaches every webview currently alive without the caller
+/// having to track them itself.
+pub(crate) struct BroadcastRegistry {
+    next_id: AtomicU64,
+    subscribers: Mutex<HashMap<u64, Box<dyn Fn(&str) + Send + Sync>>>,
+}
+
+impl BroadcastRegistry {
+    fn new() -> Self {
+        Self {
+            next_id: AtomicU64::new(0),
+            subscribers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn subscribe(&self, send: impl Fn(&str) + Send + Sync + 'static) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.subscribers.lock().insert(id, Box::new(send));
+        id
+    }
+
+    pub(crate) fn unsubscribe(&self, id: u64) {
+        self.subscribers.lock().remove(&id);
+    }
+
+    /// Fan `message` out to every subscriber except `except` (the webview a
+    /// frame was received from, if any, to avoid echoing it straight back).
+    pub(crate) fn broadcast(&self, except: Option<u64>, message: &str) {
+        for (id, send) in self.subscribers.lock().iter() {
+            if Some(*id) == except {
+                continue;
+            }
+
+            send(message);
+        }
+    }
+}
+
+/// A named pub/sub bus for pushing framed byte messages to every `WebView`
+/// sharing a `Runtime`, modeled on Deno's `InMemoryBroadcastChannel`.
+/// Created via [`Runtime::broadcast_channel`].
+///
+/// Frames are routed over each webview's existing `send_message` channel, so
+/// no per-webview wiring is required on the caller's part. Frames a webview
+/// sends back to the browser process are delivered through
+/// [`RuntimeHandler::on_broadcast`].
+pub struct BroadcastChannel {
+    name: String,
+    registry: Arc<BroadcastRegistry>,
+}
+
+/// Wire prefix identifying a `BroadcastChannel` frame inside a webview's
+/// `send_message`/`on_message` channel, the same way `Page::eval`'s reply
+/// wrapper tags its own protocol messages with a call id to distinguish them
+/// from ordinary page messages.
+pub(crate) const BROADCAST_MESSAGE_TYPE: &str = "wew_broadcast";
+
+impl BroadcastChannel {
+    /// Send `payload` to every other subscriber of this channel, i.e. every
+    /// `WebView` sharing the runtime. `RuntimeHandler::on_broadcast` is not
+    /// called for frames sent from the browser process.
+    pub fn send(&self, payload: Vec<u8>) {
+        let message = serde_json::json!({
+            "type": BROADCAST_MESSAGE_TYPE,
+            "channel": self.name,
+            "payload": payload,
+        })
+        .to_string();
+
+        self.registry.broadcast(None, &message);
+    }
+}
+
 pub(crate) struct IRuntime {
     // The runtime may use a custom request interceptor; a reference is kept here to ensure correct
     // lifetime management.
@@ -464,6 +647,36 @@ pub(crate) struct IRuntime {
     multi_threaded_message_loop: bool,
     context: ThreadSafePointer<RuntimeContext>,
     raw: Mutex<Arc<ThreadSafePointer<c_void>>>,
+    // The CDP endpoint advertised by `Runtime::devtools_endpoint`, precomputed
+    // once at construction since `remote_debugging_port` is fixed for the
+    // lifetime of the runtime.
+    devtools_endpoint: Option<String>,
+    // Closures queued by `create_webview_deferred` while the context hasn't
+    // finished initializing yet. Shared with `RuntimeContext` so
+    // `on_context_initialized_callback` can drain it.
+    pending: Arc<Mutex<Vec<Box<dyn FnOnce() + Send>>>>,
+    // Only `Some` when `with_pump_hang_threshold` was configured. Must be
+    // dropped (and thus joined) before `context` is freed below.
+    pump_watchdog: Option<PumpWatchdog>,
+    broadcast_registry: Arc<BroadcastRegistry>,
+}
+
+/// Monitor thread backing `with_pump_hang_threshold`. Polls `PUMP_DEADLINE_MS`
+/// and fires `MessagePumpRuntimeHandler::on_pump_starved` when it's overdue
+/// by more than the configured threshold.
+struct PumpWatchdog {
+    shutdown: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl Drop for PumpWatchdog {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
 }
 
 impl IRuntime {
@@ -481,6 +694,20 @@ impl IRuntime {
             return Err(Error::NonUIThread);
         }
 
+        if attr.remote_debugging_port == Some(0) {
+            return Err(Error::InvalidRemoteDebuggingPort);
+        }
+
+        let devtools_endpoint = attr.remote_debugging_port.map(|port| {
+            let host = attr
+                .remote_debugging_address
+                .as_ref()
+                .and_then(|it| it.to_str().ok())
+                .unwrap_or("127.0.0.1");
+
+            format!("http://{host}:{port}")
+        });
+
         let custom_scheme = attr
             .custom_scheme
             .as_ref()
@@ -490,6 +717,22 @@ impl IRuntime {
                 factory: attr.handler.as_raw().as_ptr(),
             });
 
+        request::set_request_origin_allowlist(attr.request_origin_allowlist.clone().unwrap_or_else(
+            || {
+                attr.custom_scheme
+                    .as_ref()
+                    .map(|it| {
+                        format!(
+                            "{}://{}",
+                            it.name.to_str().unwrap_or_default(),
+                            it.domain.to_str().unwrap_or_default()
+                        )
+                    })
+                    .into_iter()
+                    .collect()
+            },
+        ));
+
         let options = sys::RuntimeSettings {
             cache_path: attr.cache_path.as_raw(),
             root_cache_path: attr.root_cache_path.as_raw(),
@@ -511,6 +754,7 @@ impl IRuntime {
             external_message_pump: attr.external_message_pump,
             multi_threaded_message_loop: attr.multi_threaded_message_loop,
             log_severity: attr.log_severity.unwrap_or(LogLevel::Off).into(),
+            remote_debugging_port: attr.remote_debugging_port.unwrap_or(0),
             custom_scheme: custom_scheme
                 .as_ref()
                 .map(|it| it as *const _)
@@ -518,11 +762,57 @@ impl IRuntime {
         };
 
         let initialized: Arc<AtomicBool> = Default::default();
+        let pending: Arc<Mutex<Vec<Box<dyn FnOnce() + Send>>>> = Default::default();
+        let broadcast_registry = Arc::new(BroadcastRegistry::new());
         let context: *mut RuntimeContext = Box::into_raw(Box::new(RuntimeContext {
             initialized: initialized.clone(),
+            pending: pending.clone(),
             handler,
         }));
 
+        let pump_watchdog = attr.pump_hang_threshold.map(|threshold| {
+            let shutdown = Arc::new(AtomicBool::new(false));
+            let shutdown_ = shutdown.clone();
+            let context_ptr = context as usize;
+            let threshold_ms = threshold.as_millis() as u64;
+
+            let handle = thread::spawn(move || {
+                let context = unsafe { &*(context_ptr as *mut RuntimeContext) };
+                let mut last_notified_deadline = u64::MAX;
+
+                while !shutdown_.load(Ordering::Relaxed) {
+                    thread::sleep(Duration::from_millis(50));
+
+                    let deadline = PUMP_DEADLINE_MS.load(Ordering::Relaxed);
+                    if deadline == u64::MAX || deadline == last_notified_deadline {
+                        continue;
+                    }
+
+                    let now = monotonic_now_ms();
+                    if now <= deadline {
+                        continue;
+                    }
+
+                    let overdue = now - deadline;
+                    if overdue < threshold_ms {
+                        continue;
+                    }
+
+                    last_notified_deadline = deadline;
+
+                    if let MixRuntimeHnadler::MessagePumpRuntimeHandler(handler) = &context.handler
+                    {
+                        handler.on_pump_starved(overdue);
+                    }
+                }
+            });
+
+            PumpWatchdog {
+                shutdown,
+                handle: Some(handle),
+            }
+        });
+
         let ptr = unsafe {
             sys::create_runtime(
                 &options,
@@ -559,6 +849,10 @@ impl IRuntime {
 
         RUNTIME_RUNNING.store(true, Ordering::Relaxed);
 
+        if let Some(policy) = attr.activation_policy {
+            set_activation_policy(policy);
+        }
+
         Ok(Self {
             initialized,
             raw: Mutex::new(raw),
@@ -568,6 +862,10 @@ impl IRuntime {
                 .custom_scheme
                 .as_ref()
                 .map(|it| it.handler.get_shared_ref()),
+            devtools_endpoint,
+            pending,
+            pump_watchdog,
+            broadcast_registry,
         })
     }
 
@@ -578,6 +876,20 @@ impl IRuntime {
     pub(crate) fn get_raw(&self) -> Arc<ThreadSafePointer<c_void>> {
         self.raw.lock().clone()
     }
+
+    pub(crate) fn get_broadcast_registry(&self) -> Arc<BroadcastRegistry> {
+        self.broadcast_registry.clone()
+    }
+
+    /// Deliver an inbound broadcast frame to this runtime's handler, if any.
+    pub(crate) fn dispatch_broadcast(&self, name: &str, payload: &[u8]) {
+        match &unsafe { &*self.context.as_ptr() }.handler {
+            MixRuntimeHnadler::RuntimeHandler(handler) => handler.on_broadcast(name, payload),
+            MixRuntimeHnadler::MessagePumpRuntimeHandler(handler) => {
+                handler.on_broadcast(name, payload)
+            }
+        }
+    }
 }
 
 impl Drop for IRuntime {
@@ -593,6 +905,10 @@ impl Drop for IRuntime {
             sys::close_runtime(self.raw.lock().as_ptr());
         }
 
+        // Must join before freeing `context` below: the watchdog thread holds a
+        // raw pointer into it.
+        self.pump_watchdog.take();
+
         drop(unsafe { Box::from_raw(self.context.as_ptr()) });
     }
 }
@@ -618,6 +934,40 @@ impl<R, W> Runtime<R, W> {
             inner: Arc::new(IRuntime::new(attr, handler)?),
         })
     }
+
+    /// The Chrome DevTools Protocol endpoint exposed by
+    /// `with_remote_debugging_port`, reachable by a CDP client or the
+    /// Chromium DevTools frontend via `chrome://inspect`.
+    ///
+    /// Returns `None` if no remote debugging port was configured, or if the
+    /// runtime hasn't finished initializing yet.
+    pub fn devtools_endpoint(&self) -> Option<&str> {
+        if !self.inner.is_initialized() {
+            return None;
+        }
+
+        self.inner.devtools_endpoint.as_deref()
+    }
+
+    /// Open a named [`BroadcastChannel`] for pushing messages to every
+    /// `WebView` sharing this runtime (and receiving, via
+    /// `RuntimeHandler::on_broadcast`, frames webviews send back).
+    pub fn broadcast_channel(&self, name: &str) -> BroadcastChannel {
+        BroadcastChannel {
+            name: name.to_string(),
+            registry: self.inner.get_broadcast_registry(),
+        }
+    }
+
+    /// Obtain the [`CookieManager`] backing this runtime's cookie store.
+    ///
+    /// Since only one runtime may exist per process, this is currently
+    /// equivalent to [`CookieManager::global`], but is exposed here so
+    /// callers don't need to reach into a separate global to read back the
+    /// cookies a webview on this runtime has set.
+    pub fn cookie_manager(&self) -> CookieManager {
+        CookieManager::global()
+    }
 }
 
 impl<R, W> GetSharedRef for Runtime<R, W> {
@@ -628,6 +978,30 @@ impl<R, W> GetSharedRef for Runtime<R, W> {
     }
 }
 
+/// A handle to a webview queued with `create_webview_deferred`.
+///
+/// Creation only actually runs once the runtime's context has finished
+/// initializing, so the result isn't available immediately; call
+/// [`wait`](Self::wait) to block for it, or [`try_wait`](Self::try_wait) to
+/// poll without blocking.
+pub struct DeferredWebView<W> {
+    rx: mpsc::Receiver<Result<WebView<W>, Error>>,
+}
+
+impl<W> DeferredWebView<W> {
+    /// Block until the queued webview has been created, or creation failed.
+    pub fn wait(self) -> Result<WebView<W>, Error> {
+        self.rx.recv().unwrap_or(Err(Error::RuntimeNotInitialization))
+    }
+
+    /// Poll for whether the queued webview is ready yet, without blocking.
+    ///
+    /// Returns `None` if the context hasn't finished initializing yet.
+    pub fn try_wait(&self) -> Option<Result<WebView<W>, Error>> {
+        self.rx.try_recv().ok()
+    }
+}
+
 impl<R> Runtime<R, WindowlessRenderWebView> {
     pub fn create_webview<T>(
         &self,
@@ -650,6 +1024,39 @@ impl<R> Runtime<R, WindowlessRenderWebView> {
             MixWebviewHnadler::WindowlessRenderWebViewHandler(Box::new(handler)),
         )
     }
+
+    /// Queue a webview for creation instead of failing with
+    /// `Error::RuntimeNotInitialization` when the context hasn't finished
+    /// initializing yet. If the context is already initialized, the
+    /// webview is created immediately. Otherwise the creation closure runs
+    /// on the UI thread right after `on_context_initialized` fires, in the
+    /// order it was queued relative to other deferred webviews.
+    pub fn create_webview_deferred<T>(
+        &self,
+        url: &str,
+        attr: WebViewAttributes,
+        handler: T,
+    ) -> DeferredWebView<WindowlessRenderWebView>
+    where
+        T: WindowlessRenderWebViewHandler + 'static,
+        R: Clone + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+
+        if self.inner.is_initialized() {
+            let _ = tx.send(self.create_webview(url, attr, handler));
+            return DeferredWebView { rx };
+        }
+
+        let runtime = self.clone();
+        let url = url.to_string();
+
+        self.inner.pending.lock().push(Box::new(move || {
+            let _ = tx.send(runtime.create_webview(&url, attr, handler));
+        }));
+
+        DeferredWebView { rx }
+    }
 }
 
 impl<R> Runtime<R, NativeWindowWebView> {
@@ -674,6 +1081,39 @@ impl<R> Runtime<R, NativeWindowWebView> {
             MixWebviewHnadler::WebViewHandler(Box::new(handler)),
         )
     }
+
+    /// Queue a webview for creation instead of failing with
+    /// `Error::RuntimeNotInitialization` when the context hasn't finished
+    /// initializing yet. If the context is already initialized, the
+    /// webview is created immediately. Otherwise the creation closure runs
+    /// on the UI thread right after `on_context_initialized` fires, in the
+    /// order it was queued relative to other deferred webviews.
+    pub fn create_webview_deferred<T>(
+        &self,
+        url: &str,
+        attr: WebViewAttributes,
+        handler: T,
+    ) -> DeferredWebView<NativeWindowWebView>
+    where
+        T: WebViewHandler + 'static,
+        R: Clone + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel();
+
+        if self.inner.is_initialized() {
+            let _ = tx.send(self.create_webview(url, attr, handler));
+            return DeferredWebView { rx };
+        }
+
+        let runtime = self.clone();
+        let url = url.to_string();
+
+        self.inner.pending.lock().push(Box::new(move || {
+            let _ = tx.send(runtime.create_webview(&url, attr, handler));
+        }));
+
+        DeferredWebView { rx }
+    }
 }
 
 impl From<LogLevel> for sys::LogLevel {
@@ -692,6 +1132,7 @@ impl From<LogLevel> for sys::LogLevel {
 struct RuntimeContext {
     handler: MixRuntimeHnadler,
     initialized: Arc<AtomicBool>,
+    pending: Arc<Mutex<Vec<Box<dyn FnOnce() + Send>>>>,
 }
 
 pub(crate) enum MixRuntimeHnadler {
@@ -712,9 +1153,17 @@ extern "C" fn on_context_initialized_callback(context: *mut c_void) {
         MixRuntimeHnadler::RuntimeHandler(handler) => handler.on_context_initialized(),
         MixRuntimeHnadler::MessagePumpRuntimeHandler(handler) => handler.on_context_initialized(),
     }
+
+    // Run, in order, whatever `create_webview_deferred` queued up while the
+    // context was still initializing.
+    for task in context.pending.lock().drain(..) {
+        task();
+    }
 }
 
 extern "C" fn on_schedule_message_pump_work_callback(delay: i64, context: *mut c_void) {
+    PUMP_DEADLINE_MS.store(monotonic_now_ms() + delay.max(0) as u64, Ordering::Relaxed);
+
     if context.is_null() {
         return;
     }
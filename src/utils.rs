@@ -229,6 +229,49 @@ pub fn startup_nsapplication() -> bool {
     true
 }
 
+/// Mirrors `NSApplicationActivationPolicy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivationPolicy {
+    /// The application is an ordinary app that appears in the Dock and may
+    /// have a menu bar and windows.
+    Regular,
+    /// The application does not appear in the Dock and does not have a menu
+    /// bar, but may be activated programmatically or by clicking one of its
+    /// windows.
+    Accessory,
+    /// The application does not appear in the Dock and may not create
+    /// windows or be activated.
+    Prohibited,
+}
+
+/// Set the activation policy of `NSApplication` on macOS.
+///
+/// This controls whether the application shows up in the Dock and/or has a
+/// menu bar, which is useful for menu-bar utilities and background agents
+/// that should not present themselves as an ordinary foreground app. Has no
+/// effect on platforms other than macOS.
+pub fn set_activation_policy(policy: ActivationPolicy) -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        let raw: isize = match policy {
+            ActivationPolicy::Regular => 0,
+            ActivationPolicy::Accessory => 1,
+            ActivationPolicy::Prohibited => 2,
+        };
+
+        unsafe {
+            let app: *mut AnyObject = msg_send![class!(NSApplication), sharedApplication];
+            msg_send![app, setActivationPolicy: raw]
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = policy;
+        true
+    }
+}
+
 /// Abstraction for obtaining a shared reference
 ///
 /// In this project, a type usually has a corresponding shared reference type,
@@ -60,12 +60,20 @@
 //! Chromium-style window.
 
 use std::{
+    collections::HashMap,
     ffi::{CStr, CString, c_char, c_int, c_void},
+    future::Future,
     marker::PhantomData,
     mem::MaybeUninit,
     ops::Deref,
+    path::PathBuf,
+    pin::Pin,
     ptr::null,
-    sync::Arc,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll, Waker},
 };
 
 use parking_lot::Mutex;
@@ -74,10 +82,11 @@ use raw_window_handle::RawWindowHandle;
 use crate::{
     Error, Rect, WindowlessRenderWebView,
     events::{
-        IMEAction, KeyboardEvent, KeyboardEventType, KeyboardModifiers, MouseButton, MouseEvent,
+        CompositionUnderline, IMEAction, KeyboardEvent, KeyboardEventType, KeyboardModifiers,
+        MouseButton, MouseButtonEvent, MouseEvent, ScrollDelta, UnderlineStyle,
     },
     request::{CustomRequestHandlerFactory, ICustomRequestHandlerFactory},
-    runtime::{IRuntime, Runtime},
+    runtime::{BROADCAST_MESSAGE_TYPE, BroadcastRegistry, IRuntime, Runtime},
     sys,
     utils::{AnyStringCast, GetSharedRef, ThreadSafePointer},
 };
@@ -139,6 +148,145 @@ pub enum CursorType {
     NumValues = 50,
 }
 
+/// A standard cursor icon, using the winit/CSS cursor set
+///
+/// Unlike `CursorType`, which mirrors CEF's platform-agnostic cursor
+/// enum one-for-one, this is the set most windowing toolkits (and the
+/// embedder's own cursor theme) actually know how to install.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum CursorIcon {
+    Default,
+    Pointer,
+    Crosshair,
+    Text,
+    Wait,
+    Help,
+    EResize,
+    NResize,
+    NeResize,
+    NwResize,
+    SResize,
+    SeResize,
+    SwResize,
+    WResize,
+    NsResize,
+    EwResize,
+    NeswResize,
+    NwseResize,
+    ColResize,
+    RowResize,
+    AllScroll,
+    Move,
+    VerticalText,
+    Cell,
+    ContextMenu,
+    Alias,
+    Progress,
+    NoDrop,
+    Copy,
+    None,
+    NotAllowed,
+    ZoomIn,
+    ZoomOut,
+    Grab,
+    Grabbing,
+}
+
+impl CursorType {
+    /// Maps this CEF cursor type to a standard `CursorIcon`.
+    ///
+    /// `CursorType` is exhaustive over every cursor CEF can request, but
+    /// several of those (directional panning, custom bitmaps, drag-and-drop
+    /// feedback) have no distinct icon in the winit/CSS set. Those fall back
+    /// to a shared icon instead of forcing every embedder to build its own
+    /// mapping: every panning cursor degrades to an omnidirectional
+    /// `AllScroll`, and the rest to `Default`.
+    pub fn to_icon(self) -> CursorIcon {
+        match self {
+            Self::Pointer => CursorIcon::Default,
+            Self::Cross => CursorIcon::Crosshair,
+            Self::Hand => CursorIcon::Pointer,
+            Self::IBeam => CursorIcon::Text,
+            Self::Wait => CursorIcon::Wait,
+            Self::Help => CursorIcon::Help,
+            Self::EastResize => CursorIcon::EResize,
+            Self::NorthResize => CursorIcon::NResize,
+            Self::NorthEastResize => CursorIcon::NeResize,
+            Self::NorthWestResize => CursorIcon::NwResize,
+            Self::SouthResize => CursorIcon::SResize,
+            Self::SouthEastResize => CursorIcon::SeResize,
+            Self::SouthWestResize => CursorIcon::SwResize,
+            Self::WestResize => CursorIcon::WResize,
+            Self::NorthSouthResize => CursorIcon::NsResize,
+            Self::EastWestResize => CursorIcon::EwResize,
+            Self::NorthEastSouthWestResize => CursorIcon::NeswResize,
+            Self::NorthWestSouthEastResize => CursorIcon::NwseResize,
+            Self::ColumnResize => CursorIcon::ColResize,
+            Self::RowResize => CursorIcon::RowResize,
+            Self::MiddlePanning
+            | Self::EastPanning
+            | Self::NorthPanning
+            | Self::NorthEastPanning
+            | Self::NorthWestPanning
+            | Self::SouthPanning
+            | Self::SouthEastPanning
+            | Self::SouthWestPanning
+            | Self::WestPanning
+            | Self::MiddlePanningVertical
+            | Self::MiddlePanningHorizontal => CursorIcon::AllScroll,
+            Self::Move => CursorIcon::Move,
+            Self::VerticalText => CursorIcon::VerticalText,
+            Self::Cell => CursorIcon::Cell,
+            Self::ContextMenu => CursorIcon::ContextMenu,
+            Self::Alias => CursorIcon::Alias,
+            Self::Progress => CursorIcon::Progress,
+            Self::NoDrop => CursorIcon::NoDrop,
+            Self::Copy => CursorIcon::Copy,
+            Self::None => CursorIcon::None,
+            Self::NotAllowed => CursorIcon::NotAllowed,
+            Self::ZoomIn => CursorIcon::ZoomIn,
+            Self::ZoomOut => CursorIcon::ZoomOut,
+            Self::Grab => CursorIcon::Grab,
+            Self::Grabbing => CursorIcon::Grabbing,
+            // Custom bitmap cursors carry their own image via `CursorInfo`
+            // on `on_cursor_change`; drag-and-drop feedback cursors and the
+            // sentinel `NumValues` have no standard icon at all.
+            Self::Custom
+            | Self::DndNone
+            | Self::DndMove
+            | Self::DndCopy
+            | Self::DndLink
+            | Self::NumValues => CursorIcon::Default,
+        }
+    }
+}
+
+/// Pixel data for a custom cursor image
+///
+/// Delivered alongside `CursorType::Custom` from
+/// `WebViewHandler::on_cursor_change`.
+#[derive(Clone, Copy)]
+pub struct CursorInfo<'a> {
+    /// BGRA pixel buffer, `width` * `height` * 4 bytes, upper-left origin.
+    pub buffer: &'a [u8],
+    pub width: u32,
+    pub height: u32,
+    /// The cursor's hotspot, in pixels from the top-left of the image.
+    pub hotspot_x: u32,
+    pub hotspot_y: u32,
+}
+
+impl std::fmt::Debug for CursorInfo<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CursorInfo")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("hotspot_x", &self.hotspot_x)
+            .field("hotspot_y", &self.hotspot_y)
+            .finish()
+    }
+}
+
 /// Represents the type of a frame
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum FrameType {
@@ -146,6 +294,60 @@ pub enum FrameType {
     Popup,
 }
 
+/// A platform shared-texture handle delivered by
+/// `WindowlessRenderWebViewHandler::on_accelerated_frame`.
+///
+/// This is the raw handle CEF hands back from `OnAcceleratedPaint`; it is
+/// owned by CEF and only valid for the duration of that callback, so it
+/// must be imported into the embedder's GPU API (wgpu/Metal/D3D) rather
+/// than stored past the call.
+#[derive(Debug, Clone, Copy)]
+pub enum SharedTextureHandle {
+    /// `IOSurfaceID` (macOS).
+    IoSurface(u32),
+    /// Shared `HANDLE` from `ID3D11Texture2D::CreateSharedHandle` (Windows).
+    D3d11(isize),
+    /// `dmabuf`/native-pixmap file descriptor (Linux).
+    NativePixmap(i32),
+}
+
+impl SharedTextureHandle {
+    fn from_raw(handle: *mut c_void) -> Self {
+        #[cfg(target_os = "macos")]
+        {
+            Self::IoSurface(handle as u32)
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Self::D3d11(handle as isize)
+        }
+        #[cfg(target_os = "linux")]
+        {
+            Self::NativePixmap(handle as i32)
+        }
+    }
+}
+
+/// Pixel format of a `SharedTextureHandle` delivered to `on_accelerated_frame`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedTextureFormat {
+    Bgra8,
+    Nv12,
+}
+
+/// The action to take when a page tries to open a new window, returned
+/// from `WebViewHandler::on_new_window`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NewWindowAction {
+    /// Let CEF create the new browser window as usual.
+    Allow,
+    /// Drop the navigation; no new window is created.
+    Deny,
+    /// Load the requested URL in the current view instead of opening a new
+    /// window.
+    OpenInSame,
+}
+
 /// Represents a rendered frame of a web page
 #[derive(Clone, Copy)]
 pub struct Frame<'a> {
@@ -160,6 +362,11 @@ pub struct Frame<'a> {
     pub width: u32,
     /// The height of the frame
     pub height: u32,
+    /// The set of rectangles, in device pixels clamped to the frame bounds,
+    /// that changed since the previous frame. Embedders may upload just
+    /// these sub-regions instead of the whole buffer. Empty means "assume
+    /// full-surface", i.e. treat the entire frame as dirty.
+    pub dirty_rects: &'a [Rect],
 }
 
 impl std::fmt::Debug for Frame<'_> {
@@ -170,6 +377,7 @@ impl std::fmt::Debug for Frame<'_> {
             .field("y", &self.y)
             .field("width", &self.width)
             .field("height", &self.height)
+            .field("dirty_rects", &self.dirty_rects)
             .finish()
     }
 }
@@ -210,7 +418,11 @@ pub trait WebViewHandler: Send + Sync {
     ///
     /// When the web page wants to change the mouse pointer style, it will be
     /// triggered, such as moving to a link.
-    fn on_cursor_change(&self, ty: CursorType) {}
+    ///
+    /// `custom` carries the cursor's pixel data and hotspot when
+    /// `ty` is `CursorType::Custom`; it is `None` for every other cursor
+    /// type.
+    fn on_cursor_change(&self, ty: CursorType, custom: Option<&CursorInfo>) {}
     /// Called when the web page state changes
     ///
     /// You need to pay attention to status changes, determine whether loading
@@ -227,6 +439,46 @@ pub trait WebViewHandler: Send + Sync {
     ///
     /// This callback is called when a message is received from the web page.
     fn on_message(&self, message: &str) {}
+
+    /// Called when the page tries to open a new window
+    ///
+    /// This is triggered by `target="_blank"` links, `window.open`, and
+    /// middle-clicking a link. Return `NewWindowAction::Deny` to drop the
+    /// navigation, or `NewWindowAction::OpenInSame` to load `url` in this
+    /// view instead of creating a new window.
+    fn on_new_window(&self, url: &str, frame_type: FrameType) -> NewWindowAction {
+        NewWindowAction::Allow
+    }
+
+    /// Called before the main frame navigates to `url`
+    ///
+    /// Return `false` to cancel the navigation. `is_redirect` is true when
+    /// the navigation was triggered by a server or client-side redirect
+    /// rather than a direct link click or `WebView::navigate` call.
+    fn on_before_navigate(&self, url: &str, is_redirect: bool) -> bool {
+        true
+    }
+
+    /// Called when the main frame's navigation state changes
+    ///
+    /// Fired after a committed navigation, so a host can keep an address
+    /// bar and back/forward buttons in sync.
+    fn on_navigation_state_change(&self, can_go_back: bool, can_go_forward: bool, url: &str) {}
+
+    /// Called when a download is about to start
+    ///
+    /// Return `None` to cancel the download, or `Some(path)` to accept it
+    /// and save it to `path`. There is no native "Save As" dialog, so this
+    /// is the only way to observe or control downloads triggered by a page.
+    fn on_download_begin(&self, url: &str, suggested_name: &str) -> Option<PathBuf> {
+        None
+    }
+
+    /// Called as a previously accepted download makes progress
+    ///
+    /// `complete` is true on the final call for a given download, whether it
+    /// finished successfully or was cancelled.
+    fn on_download_progress(&self, received: u64, total: u64, complete: bool) {}
 }
 
 /// Windowless render web view handler
@@ -250,6 +502,76 @@ pub trait WindowlessRenderWebViewHandler: WebViewHandler {
     /// It should be noted that if the webview is resized, the width and height
     /// of the texture will also change.
     fn on_frame(&self, frame: &Frame) {}
+
+    /// Called instead of `on_frame` when `WebViewAttributes::shared_texture_enabled`
+    /// is set and CEF was able to hand back an already-composited GPU
+    /// surface via `OnAcceleratedPaint` rather than a CPU pixel buffer.
+    /// `handle` is a platform shared-texture handle (IOSurface on macOS,
+    /// D3D11 shared handle on Windows, native pixmap/dmabuf on Linux) that
+    /// can be imported zero-copy by wgpu/Metal/D3D embedders. CEF falls
+    /// back to calling `on_frame` for frames where acceleration was
+    /// unavailable, so both callbacks must be handled. `dirty_rects` is the
+    /// same per-paint change list `Frame::dirty_rects` carries.
+    fn on_accelerated_frame(
+        &self,
+        handle: SharedTextureHandle,
+        format: SharedTextureFormat,
+        width: u32,
+        height: u32,
+        dirty_rects: &[Rect],
+    ) {
+    }
+
+    /// Called when the page wants to show a JavaScript dialog
+    ///
+    /// There is no Chromium window to host a native `alert()`/`confirm()`/
+    /// `prompt()` dialog in windowless mode, so this hands control to the
+    /// embedder to render its own dialog UI over the composited frame and
+    /// feed the answer back synchronously.
+    fn on_js_dialog(&self, kind: JsDialogKind, message: &str, default_prompt: &str) -> JsDialogResult {
+        JsDialogResult::Cancel
+    }
+
+    /// Called when the page wants to show a file picker dialog
+    ///
+    /// Return `None` to cancel the dialog, or `Some(paths)` with the
+    /// selected file(s). As with `on_js_dialog`, there is no native file
+    /// picker in windowless mode, so the embedder renders its own.
+    fn on_file_dialog(&self, mode: FileDialogMode, accept_filters: &[String]) -> Option<Vec<PathBuf>> {
+        None
+    }
+}
+
+/// The kind of JavaScript dialog a page is requesting
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JsDialogKind {
+    /// `window.alert(message)`
+    Alert,
+    /// `window.confirm(message)`
+    Confirm,
+    /// `window.prompt(message, default_prompt)`
+    Prompt,
+}
+
+/// The embedder's answer to a `WindowlessRenderWebViewHandler::on_js_dialog` request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsDialogResult {
+    /// The dialog was dismissed/cancelled.
+    Cancel,
+    /// The dialog was accepted. For `Prompt` dialogs this is the entered
+    /// text; for `Alert`/`Confirm` it is ignored.
+    Accept(String),
+}
+
+/// The kind of file picker dialog a page is requesting
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FileDialogMode {
+    /// Select a single existing file.
+    Open,
+    /// Select one or more existing files.
+    OpenMultiple,
+    /// Select a destination path to save to.
+    Save,
 }
 
 /// WebView configuration attributes
@@ -292,6 +614,18 @@ pub struct WebViewAttributes {
     pub local_storage: bool,
     /// END values that map to WebPreferences settings.
     pub background_color: u32,
+    /// Proxy server used for this WebView's network traffic, overriding the
+    /// runtime's default proxy resolution.
+    pub proxy: Option<ProxyConfig>,
+    /// Overrides the User-Agent string sent for this WebView's requests.
+    pub user_agent: Option<String>,
+    /// Enable the accelerated-paint path: CEF delivers composited frames as
+    /// a platform shared texture via
+    /// `WindowlessRenderWebViewHandler::on_accelerated_frame` instead of
+    /// copying a CPU pixel buffer into `on_frame` every frame. `on_frame`
+    /// remains the fallback for any frame CEF could not accelerate. Only
+    /// applies in windowless rendering mode.
+    pub shared_texture_enabled: bool,
 }
 
 unsafe impl Send for WebViewAttributes {}
@@ -318,10 +652,26 @@ impl Default for WebViewAttributes {
             background_color: 0xFFFFFFFF,
             minimum_font_size: 12,
             minimum_logical_font_size: 12,
+            proxy: None,
+            user_agent: None,
+            shared_texture_enabled: false,
         }
     }
 }
 
+/// Proxy server configuration for a WebView's network traffic.
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    /// Proxy scheme, e.g. `"http"`, `"https"` or `"socks5"`.
+    pub scheme: String,
+    /// Proxy server host, e.g. `"127.0.0.1"`.
+    pub host: String,
+    /// Proxy server port.
+    pub port: u16,
+    /// Hosts that should bypass the proxy and be requested directly.
+    pub bypass_list: Vec<String>,
+}
+
 /// WebView configuration attributes builder
 #[derive(Default)]
 pub struct WebViewAttributesBuilder(WebViewAttributes);
@@ -490,6 +840,34 @@ impl WebViewAttributesBuilder {
         self
     }
 
+    /// Set the proxy server used for this WebView's network traffic
+    ///
+    /// This function is used to route this WebView's traffic through a
+    /// proxy, overriding the runtime's default proxy resolution.
+    pub fn with_proxy(mut self, value: ProxyConfig) -> Self {
+        self.0.proxy = Some(value);
+        self
+    }
+
+    /// Set the User-Agent string
+    ///
+    /// This function is used to override the User-Agent string sent for
+    /// this WebView's requests.
+    pub fn with_user_agent(mut self, value: &str) -> Self {
+        self.0.user_agent = Some(value.to_string());
+        self
+    }
+
+    /// Enable the accelerated-paint path
+    ///
+    /// This function is used to request that CEF deliver composited frames
+    /// as a shared GPU texture via `on_accelerated_frame` instead of a CPU
+    /// pixel buffer. Only applies in windowless rendering mode.
+    pub fn with_shared_texture_enabled(mut self, value: bool) -> Self {
+        self.0.shared_texture_enabled = value;
+        self
+    }
+
     pub fn build(self) -> WebViewAttributes {
         self.0
     }
@@ -511,6 +889,11 @@ pub(crate) struct IWebView {
     request_handler_factory: Option<Arc<ICustomRequestHandlerFactory>>,
     context: ThreadSafePointer<WebViewContext>,
     raw: Mutex<ThreadSafePointer<c_void>>,
+    next_eval_id: AtomicU64,
+    // Backs `BroadcastChannel`: this webview's `send_message` is registered
+    // under `broadcast_id` so broadcasts reach it, and removed on drop.
+    broadcast_registry: Arc<BroadcastRegistry>,
+    broadcast_id: u64,
 }
 
 impl IWebView {
@@ -523,6 +906,23 @@ impl IWebView {
         let runtime = runtime.get_shared_ref();
         let raw_runtime = runtime.get_raw();
 
+        let user_agent = attr
+            .user_agent
+            .as_ref()
+            .map(|it| CString::new(it.as_str()).unwrap());
+
+        let (proxy_scheme, proxy_host, proxy_port, proxy_bypass_list) =
+            if let Some(proxy) = &attr.proxy {
+                (
+                    Some(CString::new(proxy.scheme.as_str()).unwrap()),
+                    Some(CString::new(proxy.host.as_str()).unwrap()),
+                    proxy.port,
+                    Some(CString::new(proxy.bypass_list.join(",")).unwrap()),
+                )
+            } else {
+                (None, None, 0, None)
+            };
+
         let options = sys::WebViewSettings {
             width: attr.width,
             height: attr.height,
@@ -540,6 +940,7 @@ impl IWebView {
             windowless_frame_rate: attr.windowless_frame_rate,
             default_fixed_font_size: attr.default_fixed_font_size as _,
             default_font_size: attr.default_font_size as _,
+            shared_texture_enabled: attr.shared_texture_enabled,
             window_handle: {
                 #[cfg(not(target_os = "linux"))]
                 let mut value = null();
@@ -566,11 +967,18 @@ impl IWebView {
             } else {
                 null()
             },
+            user_agent: user_agent.as_raw(),
+            proxy_scheme: proxy_scheme.as_raw(),
+            proxy_host: proxy_host.as_raw(),
+            proxy_port,
+            proxy_bypass_list: proxy_bypass_list.as_raw(),
         };
 
         let context: *mut WebViewContext = Box::into_raw(Box::new(WebViewContext {
-            runtime: Some(runtime),
+            runtime: Some(runtime.clone()),
             handler,
+            eval_calls: Mutex::new(HashMap::new()),
+            broadcast_id: 0,
         }));
 
         let url = CString::new(url).unwrap();
@@ -584,9 +992,17 @@ impl IWebView {
                     on_state_change: Some(on_state_change_callback),
                     on_ime_rect: Some(on_ime_rect_callback),
                     on_frame: Some(on_frame_callback),
+                    on_accelerated_frame: Some(on_accelerated_frame_callback),
                     on_title_change: Some(on_title_change_callback),
                     on_fullscreen_change: Some(on_fullscreen_change_callback),
                     on_message: Some(on_message_callback),
+                    on_new_window: Some(on_new_window_callback),
+                    on_before_navigate: Some(on_before_navigate_callback),
+                    on_navigation_state_change: Some(on_navigation_state_change_callback),
+                    on_download_begin: Some(on_download_begin_callback),
+                    on_download_progress: Some(on_download_progress_callback),
+                    on_js_dialog: Some(on_js_dialog_callback),
+                    on_file_dialog: Some(on_file_dialog_callback),
                     context: context as _,
                 },
             )
@@ -598,6 +1014,21 @@ impl IWebView {
             ThreadSafePointer::new(ptr)
         };
 
+        let broadcast_registry = runtime.get_broadcast_registry();
+        let broadcast_id = {
+            let raw_ptr = raw.as_ptr() as usize;
+
+            broadcast_registry.subscribe(move |message| {
+                if let Ok(message) = CString::new(message) {
+                    unsafe { sys::webview_send_message(raw_ptr as _, message.as_raw()) };
+                }
+            })
+        };
+
+        // `context` is still exclusively ours at this point; CEF only reads
+        // it once the handler callbacks above start firing.
+        unsafe { (*context).broadcast_id = broadcast_id };
+
         Ok(Self {
             raw: Mutex::new(raw),
             context: ThreadSafePointer::new(context),
@@ -606,12 +1037,17 @@ impl IWebView {
                 .request_handler_factory
                 .as_ref()
                 .map(|it| it.get_shared_ref()),
+            next_eval_id: AtomicU64::new(0),
+            broadcast_registry,
+            broadcast_id,
         })
     }
 }
 
 impl Drop for IWebView {
     fn drop(&mut self) {
+        self.broadcast_registry.unsubscribe(self.broadcast_id);
+
         unsafe {
             sys::close_webview(self.raw.lock().as_ptr());
         }
@@ -713,6 +1149,64 @@ impl<W> WebView<W> {
     pub fn devtools_enabled(&self, enable: bool) {
         unsafe { sys::webview_set_devtools_state(self.inner.raw.lock().as_ptr(), enable) }
     }
+
+    /// Navigate the main frame to `url`.
+    pub fn navigate(&self, url: &str) {
+        let url = CString::new(url).unwrap();
+
+        unsafe { sys::webview_navigate(self.inner.raw.lock().as_ptr(), url.as_raw()) }
+    }
+
+    /// Reload the current page, optionally ignoring any cached data.
+    pub fn reload(&self, ignore_cache: bool) {
+        unsafe { sys::webview_reload(self.inner.raw.lock().as_ptr(), ignore_cache) }
+    }
+
+    /// Navigate backwards.
+    pub fn go_back(&self) {
+        unsafe { sys::webview_go_back(self.inner.raw.lock().as_ptr()) }
+    }
+
+    /// Navigate forwards.
+    pub fn go_forward(&self) {
+        unsafe { sys::webview_go_forward(self.inner.raw.lock().as_ptr()) }
+    }
+
+    /// Stop loading the page.
+    pub fn stop_load(&self) {
+        unsafe { sys::webview_stop_load(self.inner.raw.lock().as_ptr()) }
+    }
+
+    /// Evaluate `script` in the main frame and resolve with its
+    /// JSON-serialized result, or the thrown exception's message.
+    ///
+    /// Internally this wraps `script` so its return value is posted back
+    /// over the same `MessageTransport` channel `send_message` uses,
+    /// keyed by a unique call id; the reply is intercepted by
+    /// `on_message_callback` before being forwarded to
+    /// `WebViewHandler::on_message`, so callers never see the wrapper
+    /// protocol messages.
+    pub fn evaluate_script(&self, script: &str) -> EvaluateScript {
+        let id = self.inner.next_eval_id.fetch_add(1, Ordering::Relaxed);
+        let state = Arc::new(Mutex::new(EvaluateScriptState {
+            result: None,
+            waker: None,
+        }));
+
+        let context = unsafe { &*self.inner.context.as_ptr() };
+        context.eval_calls.lock().insert(id, state.clone());
+
+        let wrapped = format!(
+            "(function(){{try{{var __wew_eval_value__=(function(){{{script}}})();window.MessageTransport.send(JSON.stringify({{__wew_eval__:{id},ok:true,value:__wew_eval_value__===undefined?null:__wew_eval_value__}}));}}catch(e){{window.MessageTransport.send(JSON.stringify({{__wew_eval__:{id},ok:false,value:String(e)}}));}}}})();",
+        );
+        let wrapped = CString::new(wrapped).unwrap();
+
+        unsafe {
+            sys::webview_execute_javascript(self.inner.raw.lock().as_ptr(), wrapped.as_raw())
+        }
+
+        EvaluateScript { state }
+    }
 }
 
 impl WebView<WindowlessRenderWebView> {
@@ -756,25 +1250,101 @@ impl WebView<WindowlessRenderWebView> {
                         *event,
                         (*button).into(),
                         *is_pressed,
+                        1,
                     )
                 }
             }
         }
     }
 
+    /// Move the mouse pointer to `(x, y)`
+    ///
+    /// Note that this function only works in windowless rendering mode.
+    pub fn mouse_move(&self, x: i32, y: i32, modifiers: KeyboardModifiers) {
+        let mut event = self.inner.mouse_event.lock();
+        event.x = x;
+        event.y = y;
+        event.modifiers = event_flags(modifiers);
+
+        unsafe { sys::webview_mouse_move(self.inner.raw.lock().as_ptr(), *event) }
+    }
+
+    /// Send a mouse button press or release at `(x, y)`
+    ///
+    /// `click_count` on `event` lets the page distinguish single/double/
+    /// triple clicks.
+    ///
+    /// Note that this function only works in windowless rendering mode.
+    pub fn mouse_button(
+        &self,
+        button: MouseButton,
+        event: MouseButtonEvent,
+        x: i32,
+        y: i32,
+        modifiers: KeyboardModifiers,
+    ) {
+        let mut mouse_event = self.inner.mouse_event.lock();
+        mouse_event.x = x;
+        mouse_event.y = y;
+        mouse_event.modifiers = event_flags(modifiers);
+
+        unsafe {
+            sys::webview_mouse_click(
+                self.inner.raw.lock().as_ptr(),
+                *mouse_event,
+                button.into(),
+                event.pressed,
+                event.click_count as c_int,
+            )
+        }
+    }
+
+    /// Scroll the mouse wheel at `(x, y)` by `delta`
+    ///
+    /// Note that this function only works in windowless rendering mode.
+    pub fn mouse_wheel(&self, x: i32, y: i32, delta: ScrollDelta) {
+        let mut event = self.inner.mouse_event.lock();
+        event.x = x;
+        event.y = y;
+
+        // A physical wheel reports deltas in "lines"; CEF expects device
+        // pixels, so scale by the height of a typical scroll line.
+        const LINE_SCROLL_PIXELS: f64 = 40.0;
+
+        let (dx, dy) = match delta {
+            ScrollDelta::LineDelta(dx, dy) => (
+                dx as f64 * LINE_SCROLL_PIXELS,
+                dy as f64 * LINE_SCROLL_PIXELS,
+            ),
+            ScrollDelta::PixelDelta { x, y } => (x, y),
+        };
+
+        unsafe {
+            sys::webview_mouse_wheel(
+                self.inner.raw.lock().as_ptr(),
+                *event,
+                dx as c_int,
+                dy as c_int,
+            )
+        }
+    }
+
+    /// Notify the page that the mouse pointer left the view
+    ///
+    /// Note that this function only works in windowless rendering mode.
+    pub fn mouse_leave(&self) {
+        let event = *self.inner.mouse_event.lock();
+
+        unsafe { sys::webview_mouse_leave(self.inner.raw.lock().as_ptr(), event) }
+    }
+
     /// Send a keyboard event
     ///
     /// This function is used to send keyboard events.
     ///
     /// Note that this function only works in windowless rendering mode.
     pub fn keyboard(&self, event: &KeyboardEvent) {
-        let mut modifiers = sys::EventFlags::WEW_EVENTFLAG_NONE as u32;
-        for it in KeyboardModifiers::all() {
-            if event.modifiers.contains(it) {
-                let flag: sys::EventFlags = it.into();
-                modifiers |= flag as u32;
-            }
-        }
+        let modifiers = event_flags(event.modifiers);
 
         unsafe {
             sys::webview_keyboard(
@@ -787,6 +1357,7 @@ impl WebView<WindowlessRenderWebView> {
                     native_key_code: event.native_key_code as i32,
                     is_system_key: event.is_system_key as i32,
                     focus_on_editable_field: event.focus_on_editable_field as i32,
+                    is_repeat: event.is_repeat as i32,
                     type_: event.ty.into(),
                 },
             )
@@ -799,21 +1370,48 @@ impl WebView<WindowlessRenderWebView> {
     ///
     /// Note that this function only works in windowless rendering mode.
     pub fn ime(&self, action: &IMEAction) {
-        let input = match action {
-            IMEAction::Composition(it) | IMEAction::Pre(it, _, _) => CString::new(*it).unwrap(),
-        };
-
         match action {
-            IMEAction::Composition(_) => unsafe {
-                sys::webview_ime_composition(self.inner.raw.lock().as_ptr(), input.as_raw())
-            },
-            IMEAction::Pre(_, x, y) => unsafe {
-                sys::webview_ime_set_composition(
-                    self.inner.raw.lock().as_ptr(),
-                    input.as_raw(),
-                    *x,
-                    *y,
-                )
+            IMEAction::SetComposition {
+                text,
+                underlines,
+                selection_range,
+                replacement_range,
+            } => {
+                let text = CString::new(text.as_str()).unwrap();
+                let underlines = underlines
+                    .iter()
+                    .map(|it| sys::CompositionUnderline {
+                        range_start: it.range.0 as c_int,
+                        range_end: it.range.1 as c_int,
+                        color: it.color,
+                        background_color: it.background_color,
+                        thick: it.thick,
+                        style: it.style.into(),
+                    })
+                    .collect::<Vec<_>>();
+
+                unsafe {
+                    sys::webview_ime_set_composition(
+                        self.inner.raw.lock().as_ptr(),
+                        text.as_raw(),
+                        underlines.as_ptr(),
+                        underlines.len(),
+                        selection_range.0 as c_int,
+                        selection_range.1 as c_int,
+                        replacement_range.0 as c_int,
+                        replacement_range.1 as c_int,
+                    )
+                }
+            }
+            IMEAction::Commit(text) => {
+                let text = CString::new(text.as_str()).unwrap();
+
+                unsafe {
+                    sys::webview_ime_composition(self.inner.raw.lock().as_ptr(), text.as_raw())
+                }
+            }
+            IMEAction::Cancel => unsafe {
+                sys::webview_ime_cancel(self.inner.raw.lock().as_ptr())
             },
         }
     }
@@ -880,6 +1478,30 @@ impl From<KeyboardModifiers> for sys::EventFlags {
     }
 }
 
+/// Flattens a `KeyboardModifiers` bitflag value into the matching
+/// combination of `sys::EventFlags` bits.
+fn event_flags(modifiers: KeyboardModifiers) -> u32 {
+    let mut flags = sys::EventFlags::WEW_EVENTFLAG_NONE as u32;
+    for it in KeyboardModifiers::all() {
+        if modifiers.contains(it) {
+            let flag: sys::EventFlags = it.into();
+            flags |= flag as u32;
+        }
+    }
+    flags
+}
+
+impl From<UnderlineStyle> for sys::UnderlineStyle {
+    fn from(val: UnderlineStyle) -> Self {
+        match val {
+            UnderlineStyle::Solid => sys::UnderlineStyle::WEW_UNDERLINE_STYLE_SOLID,
+            UnderlineStyle::Dot => sys::UnderlineStyle::WEW_UNDERLINE_STYLE_DOT,
+            UnderlineStyle::Dash => sys::UnderlineStyle::WEW_UNDERLINE_STYLE_DASH,
+            UnderlineStyle::None => sys::UnderlineStyle::WEW_UNDERLINE_STYLE_NONE,
+        }
+    }
+}
+
 impl From<MouseButton> for sys::MouseButton {
     fn from(val: MouseButton) -> Self {
         match val {
@@ -893,6 +1515,37 @@ impl From<MouseButton> for sys::MouseButton {
 struct WebViewContext {
     runtime: Option<Arc<IRuntime>>,
     handler: MixWebviewHnadler,
+    eval_calls: Mutex<HashMap<u64, Arc<Mutex<EvaluateScriptState>>>>,
+    // This webview's own subscriber id in `BroadcastRegistry`, so a frame it
+    // sends isn't immediately echoed back to it.
+    broadcast_id: u64,
+}
+
+/// Shared state for a single `WebView::evaluate_script` call, completed by
+/// `on_message_callback` when the matching reply arrives.
+struct EvaluateScriptState {
+    result: Option<Result<String, Error>>,
+    waker: Option<Waker>,
+}
+
+/// The `Future` returned by `WebView::evaluate_script`.
+pub struct EvaluateScript {
+    state: Arc<Mutex<EvaluateScriptState>>,
+}
+
+impl Future for EvaluateScript {
+    type Output = Result<String, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock();
+
+        if let Some(result) = state.result.take() {
+            Poll::Ready(result)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
 }
 
 pub(crate) enum MixWebviewHnadler {
@@ -950,6 +1603,27 @@ extern "C" fn on_frame_callback(frame: *const sys::Frame, context: *mut c_void)
     let raw_frame = unsafe { &*frame };
     let context = unsafe { &*(context as *mut WebViewContext) };
 
+    let dirty_rects = if raw_frame.dirty_rects.is_null() {
+        &[]
+    } else {
+        unsafe {
+            std::slice::from_raw_parts(
+                raw_frame.dirty_rects as *const sys::Rect,
+                raw_frame.dirty_rects_len,
+            )
+        }
+    };
+
+    let dirty_rects = dirty_rects
+        .iter()
+        .map(|it| Rect {
+            x: it.x as u32,
+            y: it.y as u32,
+            width: it.width as u32,
+            height: it.height as u32,
+        })
+        .collect::<Vec<_>>();
+
     let frame = Frame {
         x: raw_frame.x as u32,
         y: raw_frame.y as u32,
@@ -966,6 +1640,7 @@ extern "C" fn on_frame_callback(frame: *const sys::Frame, context: *mut c_void)
         } else {
             FrameType::View
         },
+        dirty_rects: &dirty_rects,
     };
 
     if let MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) = &context.handler {
@@ -973,6 +1648,168 @@ extern "C" fn on_frame_callback(frame: *const sys::Frame, context: *mut c_void)
     }
 }
 
+extern "C" fn on_accelerated_frame_callback(
+    handle: *mut c_void,
+    format: c_int,
+    width: c_int,
+    height: c_int,
+    dirty_rects: *const sys::Rect,
+    dirty_rects_len: usize,
+    context: *mut c_void,
+) {
+    if context.is_null() {
+        return;
+    }
+
+    let context = unsafe { &*(context as *mut WebViewContext) };
+
+    let dirty_rects = if dirty_rects.is_null() {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(dirty_rects, dirty_rects_len) }
+    };
+
+    let dirty_rects = dirty_rects
+        .iter()
+        .map(|it| Rect {
+            x: it.x as u32,
+            y: it.y as u32,
+            width: it.width as u32,
+            height: it.height as u32,
+        })
+        .collect::<Vec<_>>();
+
+    let handle = SharedTextureHandle::from_raw(handle);
+    let format = match format {
+        0 => SharedTextureFormat::Bgra8,
+        _ => SharedTextureFormat::Nv12,
+    };
+
+    if let MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) = &context.handler {
+        handler.on_accelerated_frame(handle, format, width as u32, height as u32, &dirty_rects);
+    }
+}
+
+extern "C" fn on_js_dialog_callback(
+    kind: sys::JsDialogKind,
+    message: *const c_char,
+    default_prompt: *const c_char,
+    result_out: *mut c_char,
+    result_out_capacity: usize,
+    context: *mut c_void,
+) -> bool {
+    if context.is_null() || message.is_null() || default_prompt.is_null() {
+        return false;
+    }
+
+    let context = unsafe { &*(context as *mut WebViewContext) };
+
+    let (Ok(message), Ok(default_prompt)) = (
+        unsafe { CStr::from_ptr(message) }.to_str(),
+        unsafe { CStr::from_ptr(default_prompt) }.to_str(),
+    ) else {
+        return false;
+    };
+
+    let kind = match kind {
+        sys::JsDialogKind::WEW_JS_DIALOG_ALERT => JsDialogKind::Alert,
+        sys::JsDialogKind::WEW_JS_DIALOG_CONFIRM => JsDialogKind::Confirm,
+        sys::JsDialogKind::WEW_JS_DIALOG_PROMPT => JsDialogKind::Prompt,
+    };
+
+    let result = if let MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) =
+        &context.handler
+    {
+        handler.on_js_dialog(kind, message, default_prompt)
+    } else {
+        JsDialogResult::Cancel
+    };
+
+    let text = match result {
+        JsDialogResult::Cancel => return false,
+        JsDialogResult::Accept(text) => text,
+    };
+
+    let Ok(text) = CString::new(text) else {
+        return false;
+    };
+
+    let bytes = text.as_bytes_with_nul();
+    if bytes.len() > result_out_capacity {
+        return false;
+    }
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, result_out, bytes.len());
+    }
+
+    true
+}
+
+extern "C" fn on_file_dialog_callback(
+    mode: sys::FileDialogMode,
+    accept_filters: *const *const c_char,
+    accept_filters_len: usize,
+    paths_out: *mut c_char,
+    paths_out_capacity: usize,
+    context: *mut c_void,
+) -> bool {
+    if context.is_null() {
+        return false;
+    }
+
+    let context = unsafe { &*(context as *mut WebViewContext) };
+
+    let accept_filters = if accept_filters.is_null() {
+        Vec::new()
+    } else {
+        unsafe { std::slice::from_raw_parts(accept_filters, accept_filters_len) }
+            .iter()
+            .filter_map(|it| unsafe { CStr::from_ptr(*it) }.to_str().ok())
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    let mode = match mode {
+        sys::FileDialogMode::WEW_FILE_DIALOG_OPEN => FileDialogMode::Open,
+        sys::FileDialogMode::WEW_FILE_DIALOG_OPEN_MULTIPLE => FileDialogMode::OpenMultiple,
+        sys::FileDialogMode::WEW_FILE_DIALOG_SAVE => FileDialogMode::Save,
+    };
+
+    let paths = if let MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) =
+        &context.handler
+    {
+        handler.on_file_dialog(mode, &accept_filters)
+    } else {
+        None
+    };
+
+    let Some(paths) = paths else {
+        return false;
+    };
+
+    let joined = paths
+        .iter()
+        .map(|it| it.to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let Ok(joined) = CString::new(joined) else {
+        return false;
+    };
+
+    let bytes = joined.as_bytes_with_nul();
+    if bytes.len() > paths_out_capacity {
+        return false;
+    }
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, paths_out, bytes.len());
+    }
+
+    true
+}
+
 extern "C" fn on_title_change_callback(title: *const c_char, context: *mut c_void) {
     if context.is_null() || title.is_null() {
         return;
@@ -1012,6 +1849,14 @@ extern "C" fn on_message_callback(message: *const c_char, context: *mut c_void)
     let context = unsafe { &*(context as *mut WebViewContext) };
 
     if let Ok(message) = unsafe { CStr::from_ptr(message) }.to_str() {
+        if complete_eval_call(context, message) {
+            return;
+        }
+
+        if deliver_broadcast_frame(context, message) {
+            return;
+        }
+
         match &context.handler {
             MixWebviewHnadler::WebViewHandler(handler) => handler.on_message(message),
             MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) => {
@@ -1021,16 +1866,268 @@ extern "C" fn on_message_callback(message: *const c_char, context: *mut c_void)
     }
 }
 
-extern "C" fn on_cursor_callback(ty: sys::CursorType, context: *mut c_void) {
+/// If `message` is an `evaluate_script` reply, resolve the matching
+/// `EvaluateScript` future and return `true` so the caller does not also
+/// forward it to `WebViewHandler::on_message`.
+fn complete_eval_call(context: &WebViewContext, message: &str) -> bool {
+    let Ok(reply) = serde_json::from_str::<serde_json::Value>(message) else {
+        return false;
+    };
+
+    let Some(id) = reply.get("__wew_eval__").and_then(|it| it.as_u64()) else {
+        return false;
+    };
+
+    let Some(state) = context.eval_calls.lock().remove(&id) else {
+        return true;
+    };
+
+    let ok = reply.get("ok").and_then(|it| it.as_bool()).unwrap_or(false);
+    let value = reply.get("value").cloned().unwrap_or(serde_json::Value::Null);
+
+    let result = if ok {
+        Ok(value.to_string())
+    } else {
+        Err(Error::ScriptEvaluationFailed(
+            value.as_str().map(str::to_string).unwrap_or(value.to_string()),
+        ))
+    };
+
+    let mut state = state.lock();
+    state.result = Some(result);
+    if let Some(waker) = state.waker.take() {
+        waker.wake();
+    }
+
+    true
+}
+
+/// If `message` is a `BroadcastChannel` frame, fan it out to every other
+/// webview sharing the runtime and deliver it to
+/// `RuntimeHandler::on_broadcast`, then return `true` so the caller does not
+/// also forward it to `WebViewHandler::on_message`.
+fn deliver_broadcast_frame(context: &WebViewContext, message: &str) -> bool {
+    let Ok(frame) = serde_json::from_str::<serde_json::Value>(message) else {
+        return false;
+    };
+
+    if frame.get("type").and_then(|it| it.as_str()) != Some(BROADCAST_MESSAGE_TYPE) {
+        return false;
+    }
+
+    let Some(name) = frame.get("channel").and_then(|it| it.as_str()) else {
+        return false;
+    };
+
+    let payload: Vec<u8> = frame
+        .get("payload")
+        .and_then(|it| it.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|it| it.as_u64())
+                .map(|it| it as u8)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some(runtime) = &context.runtime {
+        runtime
+            .get_broadcast_registry()
+            .broadcast(Some(context.broadcast_id), message);
+
+        runtime.dispatch_broadcast(name, &payload);
+    }
+
+    true
+}
+
+extern "C" fn on_new_window_callback(
+    url: *const c_char,
+    is_popup: bool,
+    context: *mut c_void,
+) -> sys::NewWindowAction {
+    if context.is_null() || url.is_null() {
+        return sys::NewWindowAction::WEW_NEW_WINDOW_ALLOW;
+    }
+
+    let context = unsafe { &*(context as *mut WebViewContext) };
+    let frame_type = if is_popup {
+        FrameType::Popup
+    } else {
+        FrameType::View
+    };
+
+    let action = if let Ok(url) = unsafe { CStr::from_ptr(url) }.to_str() {
+        match &context.handler {
+            MixWebviewHnadler::WebViewHandler(handler) => handler.on_new_window(url, frame_type),
+            MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) => {
+                handler.on_new_window(url, frame_type)
+            }
+        }
+    } else {
+        NewWindowAction::Allow
+    };
+
+    match action {
+        NewWindowAction::Allow => sys::NewWindowAction::WEW_NEW_WINDOW_ALLOW,
+        NewWindowAction::Deny => sys::NewWindowAction::WEW_NEW_WINDOW_DENY,
+        NewWindowAction::OpenInSame => sys::NewWindowAction::WEW_NEW_WINDOW_OPEN_IN_SAME,
+    }
+}
+
+extern "C" fn on_before_navigate_callback(
+    url: *const c_char,
+    is_redirect: bool,
+    context: *mut c_void,
+) -> bool {
+    if context.is_null() || url.is_null() {
+        return true;
+    }
+
+    let context = unsafe { &*(context as *mut WebViewContext) };
+
+    if let Ok(url) = unsafe { CStr::from_ptr(url) }.to_str() {
+        match &context.handler {
+            MixWebviewHnadler::WebViewHandler(handler) => {
+                handler.on_before_navigate(url, is_redirect)
+            }
+            MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) => {
+                handler.on_before_navigate(url, is_redirect)
+            }
+        }
+    } else {
+        true
+    }
+}
+
+extern "C" fn on_navigation_state_change_callback(
+    can_go_back: bool,
+    can_go_forward: bool,
+    url: *const c_char,
+    context: *mut c_void,
+) {
+    if context.is_null() || url.is_null() {
+        return;
+    }
+
+    let context = unsafe { &*(context as *mut WebViewContext) };
+
+    if let Ok(url) = unsafe { CStr::from_ptr(url) }.to_str() {
+        match &context.handler {
+            MixWebviewHnadler::WebViewHandler(handler) => {
+                handler.on_navigation_state_change(can_go_back, can_go_forward, url)
+            }
+            MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) => {
+                handler.on_navigation_state_change(can_go_back, can_go_forward, url)
+            }
+        }
+    }
+}
+
+extern "C" fn on_download_begin_callback(
+    url: *const c_char,
+    suggested_name: *const c_char,
+    path_out: *mut c_char,
+    path_out_capacity: usize,
+    context: *mut c_void,
+) -> bool {
+    if context.is_null() || url.is_null() || suggested_name.is_null() {
+        return false;
+    }
+
+    let context = unsafe { &*(context as *mut WebViewContext) };
+
+    let (Ok(url), Ok(suggested_name)) = (
+        unsafe { CStr::from_ptr(url) }.to_str(),
+        unsafe { CStr::from_ptr(suggested_name) }.to_str(),
+    ) else {
+        return false;
+    };
+
+    let path = match &context.handler {
+        MixWebviewHnadler::WebViewHandler(handler) => handler.on_download_begin(url, suggested_name),
+        MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) => {
+            handler.on_download_begin(url, suggested_name)
+        }
+    };
+
+    let Some(path) = path else {
+        return false;
+    };
+
+    let Ok(path) = CString::new(path.to_string_lossy().into_owned()) else {
+        return false;
+    };
+
+    let bytes = path.as_bytes_with_nul();
+    if bytes.len() > path_out_capacity {
+        return false;
+    }
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, path_out, bytes.len());
+    }
+
+    true
+}
+
+extern "C" fn on_download_progress_callback(
+    received: u64,
+    total: u64,
+    complete: bool,
+    context: *mut c_void,
+) {
+    if context.is_null() {
+        return;
+    }
+
+    let context = unsafe { &*(context as *mut WebViewContext) };
+
+    match &context.handler {
+        MixWebviewHnadler::WebViewHandler(handler) => {
+            handler.on_download_progress(received, total, complete)
+        }
+        MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) => {
+            handler.on_download_progress(received, total, complete)
+        }
+    }
+}
+
+extern "C" fn on_cursor_callback(
+    ty: sys::CursorType,
+    custom: *const sys::CursorInfo,
+    context: *mut c_void,
+) {
     if context.is_null() {
         return;
     }
 
     let ty = unsafe { std::mem::transmute::<sys::CursorType, CursorType>(ty) };
 
+    let custom = if custom.is_null() {
+        None
+    } else {
+        let raw = unsafe { &*custom };
+        Some(CursorInfo {
+            buffer: unsafe {
+                std::slice::from_raw_parts(
+                    raw.buffer as *const u8,
+                    raw.width as usize * raw.height as usize * 4,
+                )
+            },
+            width: raw.width as u32,
+            height: raw.height as u32,
+            hotspot_x: raw.hotspot_x as u32,
+            hotspot_y: raw.hotspot_y as u32,
+        })
+    };
+
     let context = unsafe { &*(context as *mut WebViewContext) };
     match &context.handler {
-        MixWebviewHnadler::WebViewHandler(handler) => handler.on_cursor_change(ty),
-        MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) => handler.on_cursor_change(ty),
+        MixWebviewHnadler::WebViewHandler(handler) => handler.on_cursor_change(ty, custom.as_ref()),
+        MixWebviewHnadler::WindowlessRenderWebViewHandler(handler) => {
+            handler.on_cursor_change(ty, custom.as_ref())
+        }
     }
 }
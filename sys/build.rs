@@ -1,91 +1,203 @@
-use std::{env, fs, path::Path, process::Command};
-
-use anyhow::{anyhow, Result};
+use std::{
+    env, fs,
+    io::Read,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context as _, Result};
+use bzip2::read::BzDecoder;
+use sha2::{Digest, Sha256};
+
+/// Base URL CEF's prebuilt binary distributions are published under, unless
+/// overridden with `WEW_CEF_MIRROR`.
+static CEF_BINARY_BASE_URL: &str = "https://cef-builds.spotifycdn.com";
+
+/// The exact CEF/Chromium build this crate's bindings are validated against,
+/// unless overridden with `WEW_CEF_VERSION`.
+static CEF_VERSION: &str = "137.0.17+gf354b0e+chromium-137.0.7151.104";
+
+/// The CEF/Chromium version to build against: `WEW_CEF_VERSION` if set,
+/// otherwise [`CEF_VERSION`].
+fn get_cef_version() -> String {
+    env::var("WEW_CEF_VERSION").unwrap_or_else(|_| CEF_VERSION.to_string())
+}
 
-fn is_exsit(dir: &str) -> bool {
-    fs::metadata(dir).is_ok()
+/// The base URL to download the CEF distribution from: `WEW_CEF_MIRROR` if
+/// set, otherwise [`CEF_BINARY_BASE_URL`].
+fn get_cef_mirror() -> String {
+    env::var("WEW_CEF_MIRROR").unwrap_or_else(|_| CEF_BINARY_BASE_URL.to_string())
 }
 
+/// Number of attempts `download_cef` makes against the CDN before giving up.
+const DOWNLOAD_RETRIES: u32 = 3;
+
 fn join(root: &str, next: &str) -> String {
     Path::new(root).join(next).to_str().unwrap().to_string()
 }
 
-fn exec(command: &str, work_dir: &str) -> Result<String> {
-    let output = Command::new(if cfg!(windows) { "powershell" } else { "bash" })
-        .arg(if cfg!(windows) { "-command" } else { "-c" })
-        .arg(if cfg!(windows) {
-            format!("$ProgressPreference = 'SilentlyContinue';{}", command)
-        } else {
-            command.to_string()
-        })
-        .current_dir(work_dir)
-        .output()?;
-    if !output.status.success() {
-        Err(anyhow!("{}", unsafe {
-            String::from_utf8_unchecked(output.stderr)
-        }))
-    } else {
-        Ok(unsafe { String::from_utf8_unchecked(output.stdout) })
-    }
+/// The OS/arch-specific suffix CEF's CDN appends to the binary name, e.g.
+/// `linux64`, `windows64`, `macosarm64`.
+fn get_platform_suffix() -> Result<&'static str> {
+    let os = env::var("CARGO_CFG_TARGET_OS")?;
+    let arch = env::var("CARGO_CFG_TARGET_ARCH")?;
+
+    Ok(match (os.as_str(), arch.as_str()) {
+        ("windows", "x86_64") => "windows64",
+        ("windows", "aarch64") => "windowsarm64",
+        ("macos", "x86_64") => "macosx64",
+        ("macos", "aarch64") => "macosarm64",
+        ("linux", "x86_64") => "linux64",
+        ("linux", "aarch64") => "linuxarm64",
+        (os, arch) => {
+            return Err(anyhow!(
+                "unsupported target {os}/{arch} for CEF binary distribution"
+            ));
+        }
+    })
 }
 
-static URL: &'static str = "https://github.com/mycrl/webview-rs/releases/download/distributions";
+/// The versioned archive name CEF's CDN publishes, without extension, e.g.
+/// `cef_binary_137.0.17+gf354b0e+chromium-137.0.7151.104_linux64`.
+fn get_binary_name() -> Result<String> {
+    Ok(format!(
+        "cef_binary_{}_{}",
+        get_cef_version(),
+        get_platform_suffix()?
+    ))
+}
 
-fn main() -> Result<()> {
-    println!("cargo:rerun-if-changed=./cxx");
-    println!("cargo:rerun-if-changed=./src");
-    println!("cargo:rerun-if-changed=./build.rs");
+/// The full `.tar.bz2` download URL for `binary_name`.
+fn get_binary_url(binary_name: &str) -> String {
+    format!("{}/{binary_name}.tar.bz2", get_cef_mirror())
+}
 
-    let out_dir = env::var("OUT_DIR")?;
-    let cef_path: &str = &join(&out_dir, "cef");
+/// Directory already-extracted CEF distributions are cached under, keyed by
+/// [`get_binary_name`], so repeated builds (including after a `cargo clean`,
+/// which wipes `OUT_DIR`) reuse the same ~1GB distribution instead of
+/// re-downloading and recompiling it. `WEW_CACHE_DIR` overrides the location;
+/// otherwise falls back to the system temp directory.
+fn get_cache_dir() -> Result<PathBuf> {
+    let dir = match env::var("WEW_CACHE_DIR") {
+        Ok(dir) => PathBuf::from(dir),
+        Err(_) => env::temp_dir().join("wew-cef-cache"),
+    };
 
-    #[cfg(target_os = "windows")]
-    if !is_exsit(cef_path) {
-        exec(
-            &format!(
-                "Invoke-WebRequest -Uri {URL}/cef-windows-{}.zip -OutFile ./cef.zip",
-                env::var("CARGO_CFG_TARGET_ARCH")?
-            ),
-            &out_dir,
-        )?;
-
-        exec("Expand-Archive -Path cef.zip -DestinationPath ./", &out_dir)?;
-        exec("Remove-Item ./cef.zip", &out_dir)?;
+    fs::create_dir_all(&dir).context("failed to create CEF cache directory")?;
+
+    Ok(dir)
+}
+
+/// The expected SHA-256 of `binary_name`'s `.tar.bz2`, checked before
+/// extraction so a truncated or tampered CDN download fails the build rather
+/// than silently producing a broken binary. `WEW_CEF_SHA256` overrides it;
+/// otherwise it's read from the mirror's `<archive>.tar.bz2.sha256` sidecar
+/// file, which is published alongside every CEF CDN artifact.
+fn expected_sha256(binary_name: &str) -> Result<String> {
+    if let Ok(sha256) = env::var("WEW_CEF_SHA256") {
+        return Ok(sha256.trim().to_lowercase());
     }
 
-    #[cfg(target_os = "macos")]
-    if !is_exsit(cef_path) {
-        exec(
-            &format!(
-                "wget {URL}/cef-macos-{}.zip -O ./cef.zip",
-                env::var("CARGO_CFG_TARGET_ARCH")?
-            ),
-            &out_dir,
-        )?;
-
-        exec("tar -xf ./cef.zip -C ./", &out_dir)?;
-        exec("rm -f ./cef.zip", &out_dir)?;
-        exec(
-            "mv ./cef/Release/cef_sandbox.a ./cef/Release/libcef_sandbox.a",
-            &out_dir,
-        )?;
+    let url = format!("{}.sha256", get_binary_url(binary_name));
+    let body = ureq::get(&url)
+        .call()
+        .context("failed to fetch CEF distribution checksum")?
+        .into_body()
+        .read_to_string()
+        .context("failed to read CEF distribution checksum")?;
+
+    Ok(body
+        .split_whitespace()
+        .next()
+        .unwrap_or(&body)
+        .trim()
+        .to_lowercase())
+}
+
+/// Download and extract the CEF distribution into the shared cache,
+/// retrying transient HTTP failures with exponential backoff and verifying the
+/// archive's SHA-256 before extraction. No-op if the cached distribution
+/// already exists. Returns the path to the extracted distribution.
+fn download_cef() -> Result<String> {
+    let binary_name = get_binary_name()?;
+    let cef_dir = get_cache_dir()?.join(&binary_name);
+
+    if cef_dir.exists() {
+        return Ok(cef_dir.to_str().unwrap().to_string());
+    }
+
+    let url = get_binary_url(&binary_name);
+    let expected_sha256 = expected_sha256(&binary_name)?;
+
+    let mut last_err = None;
+
+    for attempt in 0..DOWNLOAD_RETRIES {
+        if attempt > 0 {
+            std::thread::sleep(Duration::from_secs(1 << attempt));
+        }
+
+        match ureq::get(&url).call() {
+            Ok(response) => {
+                let mut archive = Vec::new();
+                response
+                    .into_body()
+                    .into_reader()
+                    .read_to_end(&mut archive)
+                    .context("failed to read CEF distribution")?;
+
+                let actual_sha256 = format!("{:x}", Sha256::digest(&archive));
+                if actual_sha256 != expected_sha256 {
+                    return Err(anyhow!(
+                        "CEF distribution checksum mismatch for {binary_name}: expected {expected_sha256}, got {actual_sha256}"
+                    ));
+                }
+
+                // The archive's top-level directory is already named
+                // `binary_name`, and `cef_dir` is that same name under the
+                // cache dir, so unpacking straight into the cache dir lands
+                // the distribution at `cef_dir` with no rename needed.
+                tar::Archive::new(BzDecoder::new(&archive[..]))
+                    .unpack(cef_dir.parent().unwrap())
+                    .context("failed to extract CEF distribution")?;
+
+                #[cfg(target_os = "macos")]
+                {
+                    let sandbox = cef_dir.join("Release/cef_sandbox.a");
+                    if sandbox.exists() {
+                        fs::rename(&sandbox, cef_dir.join("Release/libcef_sandbox.a"))?;
+                    }
+                }
+
+                return Ok(cef_dir.to_str().unwrap().to_string());
+            }
+            Err(err) => last_err = Some(err),
+        }
     }
 
-    if !is_exsit(&join(cef_path, "./libcef_dll_wrapper")) {
-        #[cfg(not(target_os = "windows"))]
-        exec(
-            "cmake \
-            -DCMAKE_CXX_FLAGS=\"-Wno-deprecated-builtins\" \
-            -DCMAKE_BUILD_TYPE=Release .",
-            cef_path,
-        )?;
+    Err(anyhow!(
+        "failed to download CEF distribution from {url} after {DOWNLOAD_RETRIES} attempts: {}",
+        last_err.unwrap()
+    ))
+}
+
+/// Build `libcef_dll_wrapper` via the `cmake` crate instead of shelling out.
+/// Building a single target (rather than the default `install`) means the
+/// crate never tries to run an install step `libcef_dll_wrapper` doesn't
+/// define; it returns the directory the target was built in, which callers
+/// combine with the target name for a `cargo:rustc-link-search` entry.
+fn make_cef(cef_dir: &str) -> PathBuf {
+    let mut config = cmake::Config::new(cef_dir);
 
-        #[cfg(target_os = "windows")]
-        exec("cmake -DCMAKE_BUILD_TYPE=Release .", cef_path)?;
+    config.profile("Release").build_target("libcef_dll_wrapper");
 
-        exec("cmake --build . --config Release", cef_path)?;
+    if !cfg!(target_os = "windows") {
+        config.cxxflag("-Wno-deprecated-builtins");
     }
 
+    config.build().join("build")
+}
+
+fn make_bindgen(out_dir: &str) -> Result<()> {
     bindgen::Builder::default()
         .default_enum_style(bindgen::EnumVariation::Rust {
             non_exhaustive: false,
@@ -95,67 +207,69 @@ fn main() -> Result<()> {
         .size_t_is_usize(true)
         .header("./cxx/webview.h")
         .generate()?
-        .write_to_file(&join(&out_dir, "bindings.rs"))?;
+        .write_to_file(&join(out_dir, "bindings.rs"))?;
 
-    {
-        let mut cfgs = cc::Build::new();
-        let is_debug = env::var("DEBUG")
-            .map(|label| label == "true")
-            .unwrap_or(true);
-
-        cfgs.cpp(true)
-            .debug(is_debug)
-            .static_crt(true)
-            .target(&env::var("TARGET")?)
-            .warnings(false)
-            .out_dir(&out_dir);
-
-        if cfg!(target_os = "windows") {
-            cfgs.flag("/std:c++20");
-        } else {
-            cfgs.flag("-std=c++20");
-        }
+    Ok(())
+}
 
-        cfgs.file("./cxx/app.cpp")
-            .file("./cxx/page.cpp")
-            .file("./cxx/control.cpp")
-            .file("./cxx/render.cpp")
-            .file("./cxx/display.cpp")
-            .file("./cxx/webview.cpp")
-            .file("./cxx/scheme_handler.cpp");
-
-        cfgs.include(cef_path);
-
-        #[cfg(target_os = "windows")]
-        cfgs.define("WIN32", Some("1"))
-            .define("_WINDOWS", None)
-            .define("__STDC_CONSTANT_MACROS", None)
-            .define("__STDC_FORMAT_MACROS", None)
-            .define("_WIN32", None)
-            .define("UNICODE", None)
-            .define("_UNICODE", None)
-            .define("WINVER", Some("0x0A00"))
-            .define("_WIN32_WINNT", Some("0x0A00"))
-            .define("NTDDI_VERSION", Some("NTDDI_WIN10_FE"))
-            .define("NOMINMAX", None)
-            .define("WIN32_LEAN_AND_MEAN", None)
-            .define("_HAS_EXCEPTIONS", Some("0"))
-            .define("PSAPI_VERSION", Some("1"))
-            .define("CEF_USE_SANDBOX", None)
-            .define("CEF_USE_ATL", None)
-            .define("_HAS_ITERATOR_DEBUGGING", Some("0"));
-
-        #[cfg(target_os = "linux")]
-        cfgs.define("LINUX", Some("1")).define("CEF_X11", Some("1"));
-
-        #[cfg(target_os = "macos")]
-        cfgs.define("MACOS", Some("1"));
-
-        cfgs.compile("sys");
+fn make_library(out_dir: &str, cef_dir: &str, wrapper_build_dir: &Path) -> Result<()> {
+    let mut cfgs = cc::Build::new();
+    let is_debug = env::var("DEBUG")
+        .map(|label| label == "true")
+        .unwrap_or(true);
+
+    cfgs.cpp(true)
+        .debug(is_debug)
+        .static_crt(true)
+        .target(&env::var("TARGET")?)
+        .warnings(false)
+        .out_dir(out_dir);
+
+    if cfg!(target_os = "windows") {
+        cfgs.flag("/std:c++20");
+    } else {
+        cfgs.flag("-std=c++20");
     }
 
+    cfgs.file("./cxx/app.cpp")
+        .file("./cxx/page.cpp")
+        .file("./cxx/control.cpp")
+        .file("./cxx/render.cpp")
+        .file("./cxx/display.cpp")
+        .file("./cxx/webview.cpp")
+        .file("./cxx/scheme_handler.cpp");
+
+    cfgs.include(cef_dir);
+
+    #[cfg(target_os = "windows")]
+    cfgs.define("WIN32", Some("1"))
+        .define("_WINDOWS", None)
+        .define("__STDC_CONSTANT_MACROS", None)
+        .define("__STDC_FORMAT_MACROS", None)
+        .define("_WIN32", None)
+        .define("UNICODE", None)
+        .define("_UNICODE", None)
+        .define("WINVER", Some("0x0A00"))
+        .define("_WIN32_WINNT", Some("0x0A00"))
+        .define("NTDDI_VERSION", Some("NTDDI_WIN10_FE"))
+        .define("NOMINMAX", None)
+        .define("WIN32_LEAN_AND_MEAN", None)
+        .define("_HAS_EXCEPTIONS", Some("0"))
+        .define("PSAPI_VERSION", Some("1"))
+        .define("CEF_USE_SANDBOX", None)
+        .define("CEF_USE_ATL", None)
+        .define("_HAS_ITERATOR_DEBUGGING", Some("0"));
+
+    #[cfg(target_os = "linux")]
+    cfgs.define("LINUX", Some("1")).define("CEF_X11", Some("1"));
+
+    #[cfg(target_os = "macos")]
+    cfgs.define("MACOS", Some("1"));
+
+    cfgs.compile("sys");
+
     println!("cargo:rustc-link-lib=static=sys");
-    println!("cargo:rustc-link-search=all={}", &out_dir);
+    println!("cargo:rustc-link-search=all={}", out_dir);
 
     #[cfg(target_os = "windows")]
     {
@@ -167,13 +281,14 @@ fn main() -> Result<()> {
         println!("cargo:rustc-link-arg=/NODEFAULTLIB:libcmt.lib");
         println!(
             "cargo:rustc-link-search=all={}",
-            join(cef_path, "./libcef_dll_wrapper/Release")
+            join(cef_dir, "./libcef_dll_wrapper/Release")
         );
-
         println!(
             "cargo:rustc-link-search=all={}",
-            join(cef_path, "./Release")
+            wrapper_build_dir.join("libcef_dll_wrapper/Release").display()
         );
+
+        println!("cargo:rustc-link-search=all={}", join(cef_dir, "./Release"));
     }
 
     #[cfg(target_os = "linux")]
@@ -181,6 +296,10 @@ fn main() -> Result<()> {
         println!("cargo:rustc-link-lib=cef");
         println!("cargo:rustc-link-lib=cef_dll_wrapper");
         println!("cargo:rustc-link-lib=X11");
+        println!(
+            "cargo:rustc-link-search=all={}",
+            wrapper_build_dir.join("libcef_dll_wrapper").display()
+        );
     }
 
     #[cfg(target_os = "macos")]
@@ -188,20 +307,99 @@ fn main() -> Result<()> {
         println!("cargo:rustc-link-lib=framework=Chromium Embedded Framework");
         println!(
             "cargo:rustc-link-search=framework={}",
-            join(cef_path, "./Release")
+            join(cef_dir, "./Release")
         );
 
         println!("cargo:rustc-link-lib=cef_dll_wrapper");
         println!(
             "cargo:rustc-link-search=all={}",
-            join(cef_path, "./libcef_dll_wrapper")
+            join(cef_dir, "./libcef_dll_wrapper")
         );
-
         println!(
-            "cargo:rustc-link-search=native={}",
-            join(cef_path, "Release")
+            "cargo:rustc-link-search=all={}",
+            wrapper_build_dir.join("libcef_dll_wrapper").display()
         );
+
+        println!("cargo:rustc-link-search=native={}", join(cef_dir, "Release"));
+    }
+
+    Ok(())
+}
+
+/// True for lightweight analysis builds — docs.rs, `cargo doc`, or an
+/// rust-analyzer background check — where generating bindings is enough and
+/// the multi-hundred-MB CEF download plus C++ compile would only slow down
+/// every keystroke. Mirrors `rusty_v8`'s detection of RLS/`cargo doc` builds.
+fn is_lightweight_build() -> bool {
+    if env::var_os("DOCS_RS").is_some() {
+        return true;
+    }
+
+    if env::var_os("RUSTDOCFLAGS").is_some() {
+        return true;
     }
 
+    let Ok(cargo) = env::var("CARGO") else {
+        return false;
+    };
+
+    matches!(
+        Path::new(&cargo).file_stem().and_then(|stem| stem.to_str()),
+        Some("rls") | Some("rust-analyzer")
+    )
+}
+
+/// How `main` obtains the CEF distribution to build against.
+enum CefStrategy {
+    /// Download the pinned version from [`CEF_BINARY_BASE_URL`] (the
+    /// default).
+    Download,
+    /// Use an already-extracted CEF distribution the caller points at via
+    /// `WEW_CEF_PATH`, skipping `download_cef` entirely.
+    System,
+}
+
+impl CefStrategy {
+    /// Reads `WEW_CEF_STRATEGY`, defaulting to [`CefStrategy::Download`].
+    fn from_env() -> Result<Self> {
+        match env::var("WEW_CEF_STRATEGY").as_deref() {
+            Err(_) | Ok("download") => Ok(Self::Download),
+            Ok("system") => Ok(Self::System),
+            Ok(other) => Err(anyhow!(
+                "unknown WEW_CEF_STRATEGY {other:?}, expected \"download\" or \"system\""
+            )),
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    println!("cargo:rerun-if-changed=./cxx");
+    println!("cargo:rerun-if-changed=./src");
+    println!("cargo:rerun-if-changed=./build.rs");
+    println!("cargo:rerun-if-env-changed=WEW_CEF_STRATEGY");
+    println!("cargo:rerun-if-env-changed=WEW_CEF_PATH");
+    println!("cargo:rerun-if-env-changed=WEW_CEF_VERSION");
+    println!("cargo:rerun-if-env-changed=WEW_CEF_MIRROR");
+    println!("cargo:rerun-if-env-changed=WEW_CACHE_DIR");
+    println!("cargo:rerun-if-env-changed=WEW_CEF_SHA256");
+    println!("cargo:rerun-if-env-changed=DOCS_RS");
+    println!("cargo:rerun-if-env-changed=RUSTDOCFLAGS");
+
+    let out_dir = env::var("OUT_DIR")?;
+
+    if is_lightweight_build() {
+        return make_bindgen(&out_dir);
+    }
+
+    let cef_dir = match CefStrategy::from_env()? {
+        CefStrategy::Download => download_cef()?,
+        CefStrategy::System => env::var("WEW_CEF_PATH")
+            .context("WEW_CEF_STRATEGY=system requires WEW_CEF_PATH to point at a CEF distribution")?,
+    };
+
+    let wrapper_build_dir = make_cef(&cef_dir);
+    make_bindgen(&out_dir)?;
+    make_library(&out_dir, &cef_dir, &wrapper_build_dir)?;
+
     Ok(())
 }
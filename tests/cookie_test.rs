@@ -17,6 +17,7 @@ mod tests {
             expires: Some(1234567890),
             same_site: SameSite::Strict,
             priority: Priority::High,
+            partition_key: None,
         };
 
         assert_eq!(cookie.name, "test_cookie");
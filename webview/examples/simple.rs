@@ -7,7 +7,7 @@ use std::{
 use minifb::{MouseButton, MouseMode, Window, WindowOptions};
 use webview::{
     execute_subprocess, is_subprocess, ActionState, App, AppObserver, AppOptions, MouseAction,
-    MouseButtons, PageObserver, PageOptions, Position,
+    MouseButtons, PageObserver, PageOptions, Position, Rect,
 };
 
 struct ImplPageObserver {
@@ -15,7 +15,7 @@ struct ImplPageObserver {
 }
 
 impl PageObserver for ImplPageObserver {
-    fn on_frame(&self, buf: &[u8], _: u32, _: u32) {
+    fn on_frame(&self, buf: &[u8], _: &[Rect], _: u32, _: u32) {
         self.sender.send(buf.to_vec()).unwrap();
     }
 }
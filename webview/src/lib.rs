@@ -1,15 +1,21 @@
 mod page;
 
 use std::{
+    cell::UnsafeCell,
     env::args,
-    ffi::{c_char, c_int},
-    sync::Arc,
+    ffi::{c_char, c_int, c_void},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, OnceLock,
+    },
     thread,
 };
 
-pub use self::page::{Page, PageObserver, PageOptions};
+pub use self::page::{
+    Page, PageObserver, PageOptions, SharedTextureFormat, SharedTextureHandle,
+};
 
-pub use webview_sys::{Modifiers, MouseButtons, PageState, TouchEventType, TouchPointerType};
+pub use webview_sys::{Modifiers, MouseButtons, PageState, Rect, TouchEventType, TouchPointerType};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Position {
@@ -77,6 +83,7 @@ pub fn execute_subprocess() -> Result<(), std::io::Error> {
     if code == 0 {
         Ok(())
     } else {
+        log::error!("webview: subprocess exited with code {}", code);
         Err(std::io::Error::new(
             std::io::ErrorKind::Other,
             format!("code = {}", code),
@@ -88,16 +95,266 @@ pub fn is_subprocess() -> bool {
     args().find(|v| v.contains("--type")).is_some()
 }
 
-#[derive(Debug, Default)]
+/// Check whether the current thread is CEF's UI thread, the thread native
+/// window operations (such as `Page::reparent`) must run on.
+pub fn is_main_thread() -> bool {
+    unsafe { webview_sys::is_main_thread() }
+}
+
+/// Post a task to the main thread for execution.
+///
+/// Please note that you should not post blocking tasks, as this will
+/// severely affect the main thread message loop.
+pub fn post_main<T>(task: T) -> bool
+where
+    T: FnOnce() + Send + 'static,
+{
+    extern "C" fn post_main_callback(context: *mut c_void) {
+        if context.is_null() {
+            return;
+        }
+
+        (unsafe { Box::from_raw(context as *mut Box<dyn FnOnce() + Send>) })();
+    }
+
+    unsafe {
+        webview_sys::post_task_with_main_thread(
+            Some(post_main_callback),
+            Box::into_raw(Box::new(Box::new(task))) as _,
+        )
+    }
+}
+
+/// A single-producer/single-consumer ring buffer of boxed closures destined
+/// for the main thread. Rather than posting one CEF task per closure (as
+/// [`post_main`] does), [`MainThreadQueue::push`] only posts a single drain
+/// task when the queue transitions from empty to non-empty, so a burst of
+/// small tasks (animation ticks, streaming updates) costs one CEF post
+/// instead of many. Modeled on the wait-free SPSC ring buffers (e.g. `rtrb`)
+/// baseview uses for the same problem.
+pub struct MainThreadQueue {
+    slots: Box<[UnsafeCell<Option<Box<dyn FnOnce() + Send>>>]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+    scheduled: AtomicBool,
+}
+
+unsafe impl Sync for MainThreadQueue {}
+
+impl MainThreadQueue {
+    /// Create a queue able to hold up to `capacity` pending tasks at once.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            slots: (0..=capacity.max(1)).map(|_| UnsafeCell::new(None)).collect(),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            scheduled: AtomicBool::new(false),
+        }
+    }
+
+    /// Enqueue `task`, preserving FIFO order. Posts exactly one CEF drain
+    /// task the first time this call finds the queue empty; later pushes
+    /// ride along with that already-scheduled drain. If the ring is full,
+    /// falls back to posting `task` directly via [`post_main`] so it is
+    /// never silently dropped.
+    pub fn push(&'static self, task: impl FnOnce() + Send + 'static) {
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        let next = (tail + 1) % self.slots.len();
+
+        if next == head {
+            post_main(task);
+            return;
+        }
+
+        unsafe {
+            *self.slots[tail].get() = Some(Box::new(task));
+        }
+        self.tail.store(next, Ordering::Release);
+
+        if self
+            .scheduled
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            post_main(move || self.drain());
+        }
+    }
+
+    /// Pop and run every closure currently queued, in order. Only ever
+    /// runs on the main thread, either from the CEF drain task [`push`]
+    /// schedules or from the regular message-pump cadence.
+    ///
+    /// [`push`]: Self::push
+    pub fn drain(&self) {
+        loop {
+            while self.pop_and_run() {}
+
+            self.scheduled.store(false, Ordering::Release);
+
+            // A push racing with the line above may have seen `scheduled`
+            // still set and skipped posting a new drain task; re-check for
+            // work before giving up the main-thread slot.
+            if self.head.load(Ordering::Acquire) == self.tail.load(Ordering::Acquire) {
+                break;
+            }
+        }
+    }
+
+    fn pop_and_run(&self) -> bool {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+
+        if head == tail {
+            return false;
+        }
+
+        let task = unsafe { (*self.slots[head].get()).take() };
+        self.head.store((head + 1) % self.slots.len(), Ordering::Release);
+
+        if let Some(task) = task {
+            task();
+        }
+
+        true
+    }
+}
+
+static MAIN_THREAD_QUEUE: OnceLock<MainThreadQueue> = OnceLock::new();
+
+fn main_thread_queue() -> &'static MainThreadQueue {
+    MAIN_THREAD_QUEUE.get_or_init(|| MainThreadQueue::new(1024))
+}
+
+/// Post a task to the main thread, batched with other pending
+/// `post_main_batched` tasks behind a single CEF drain task instead of one
+/// post per call. See [`MainThreadQueue`].
+pub fn post_main_batched<T>(task: T)
+where
+    T: FnOnce() + Send + 'static,
+{
+    main_thread_queue().push(task);
+}
+
+#[derive(Default)]
 pub struct AppOptions<'a> {
     pub windowless_rendering_enabled: bool,
+    /// Maps to `cef_settings_t.cache_path`. Directory used to store cache
+    /// data such as cookies, localStorage and IndexedDB, shared by every
+    /// page that does not request its own profile via
+    /// `PageOptions::request_context_path`. When unset, CEF falls back to
+    /// an in-memory cache and nothing persists across restarts.
     pub cache_dir_path: Option<&'a str>,
+    /// Maps to `cef_settings_t.root_cache_path`. The root directory that
+    /// `cache_dir_path` and any per-page `request_context_path` must live
+    /// under; CEF resolves `DIR_USER_DATA` relative to this path. Required
+    /// on some platforms whenever `cache_dir_path` is set.
+    pub root_cache_path: Option<&'a str>,
+    /// Path to the helper executable CEF should relaunch for sub-processes.
+    /// When unset, falls back to the current process executable.
     pub browser_subprocess_path: Option<&'a str>,
     pub scheme_dir_path: Option<&'a str>,
-    #[cfg(target_os = "macos")]
+    /// Path to the `Chromium Embedded Framework.framework` bundle (macOS
+    /// only). When unset, falls back to the bundle-relative default used by
+    /// `wrap_wew` (`Contents/Frameworks` next to the main executable).
     pub framework_dir_path: Option<&'a str>,
     #[cfg(target_os = "macos")]
     pub main_bundle_path: Option<&'a str>,
+    /// Arbitrary Chromium/CEF command-line switches (e.g.
+    /// `("disable-gpu", None)`, `("proxy-server", Some("http://127.0.0.1:8080"))`)
+    /// applied to the command line before the browser process initializes.
+    pub command_line_switches: Vec<(&'a str, Option<&'a str>)>,
+    /// Maps to `cef_settings_t.log_severity`. Each CEF log record that is
+    /// bridged into the `log` crate is forwarded at the `log::Level` matching
+    /// that record's own severity (see `wrapper::on_log_message`).
+    pub log_severity: LogSeverity,
+    /// Maps to `cef_settings_t.log_file`. When unset, CEF writes to its
+    /// platform default log location. CEF log records are forwarded to the
+    /// `log` crate regardless of this setting.
+    pub log_file: Option<&'a str>,
+    /// Custom schemes registered through `register_scheme`, resolved by
+    /// their handler instead of CEF's default network stack. Must be
+    /// populated before this `AppOptions` is passed to `App::new`, since
+    /// CEF registers custom schemes during its own startup and cannot add
+    /// more afterward.
+    pub scheme_handlers: Vec<(String, Arc<dyn SchemeHandler>)>,
+}
+
+impl std::fmt::Debug for AppOptions<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut f = f.debug_struct("AppOptions");
+
+        f.field("windowless_rendering_enabled", &self.windowless_rendering_enabled)
+            .field("cache_dir_path", &self.cache_dir_path)
+            .field("root_cache_path", &self.root_cache_path)
+            .field("browser_subprocess_path", &self.browser_subprocess_path)
+            .field("scheme_dir_path", &self.scheme_dir_path)
+            .field("framework_dir_path", &self.framework_dir_path);
+
+        #[cfg(target_os = "macos")]
+        f.field("main_bundle_path", &self.main_bundle_path);
+
+        f.field("command_line_switches", &self.command_line_switches)
+            .field("log_severity", &self.log_severity)
+            .field("log_file", &self.log_file)
+            .field(
+                "scheme_handlers",
+                &self.scheme_handlers.iter().map(|(scheme, _)| scheme).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<'a> AppOptions<'a> {
+    /// Register `scheme` (e.g. `"app"`) to be resolved by `handler`
+    /// instead of CEF's default network stack, so a URL like
+    /// `app://index.html` is routed to `handler.handle`.
+    pub fn register_scheme(&mut self, scheme: impl Into<String>, handler: impl SchemeHandler + 'static) {
+        self.scheme_handlers.push((scheme.into(), Arc::new(handler)));
+    }
+}
+
+/// Mirrors `cef_log_severity_t`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LogSeverity {
+    #[default]
+    Default = 0,
+    Verbose = 1,
+    Debug = 2,
+    Info = 3,
+    Warning = 4,
+    Error = 5,
+    Fatal = 6,
+    Disable = 99,
+}
+
+impl LogSeverity {
+    /// Maps a `cef_log_severity_t` value received from a log record to the
+    /// matching `LogSeverity`; unknown values map to `Default`.
+    fn from_raw(value: c_int) -> Self {
+        match value {
+            1 => Self::Verbose,
+            2 => Self::Debug,
+            3 => Self::Info,
+            4 => Self::Warning,
+            5 => Self::Error,
+            6 => Self::Fatal,
+            99 => Self::Disable,
+            _ => Self::Default,
+        }
+    }
+
+    /// The `log::Level` a record of this severity is forwarded at, or `None`
+    /// for severities that shouldn't be forwarded at all.
+    fn to_log_level(self) -> Option<log::Level> {
+        match self {
+            Self::Verbose | Self::Debug => Some(log::Level::Debug),
+            Self::Default | Self::Info => Some(log::Level::Info),
+            Self::Warning => Some(log::Level::Warn),
+            Self::Error | Self::Fatal => Some(log::Level::Error),
+            Self::Disable => None,
+        }
+    }
 }
 
 #[allow(unused_variables)]
@@ -106,6 +363,56 @@ pub trait AppObserver {
     fn on_schedule_message_pump_work(&self, delay: u64) {}
 }
 
+/// A single request made against a custom scheme registered through
+/// `AppOptions::register_scheme`.
+#[derive(Debug, Clone)]
+pub struct SchemeRequest {
+    /// The full request URL, e.g. `app://index.html`.
+    pub url: String,
+    /// The HTTP method, e.g. `"GET"`.
+    pub method: String,
+    pub headers: Vec<(String, String)>,
+}
+
+/// The response to a `SchemeRequest`: a status code, headers, and a
+/// pull-based body reader. CEF calls `body.read` repeatedly for chunks
+/// rather than requiring the whole response in memory up front.
+pub struct SchemeResponse {
+    pub status: u16,
+    pub mime_type: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Box<dyn std::io::Read + Send>,
+}
+
+impl SchemeResponse {
+    /// Build a response serving `body` in full from memory.
+    pub fn new(status: u16, mime_type: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            mime_type: mime_type.into(),
+            headers: Vec::new(),
+            body: Box::new(std::io::Cursor::new(body.into())),
+        }
+    }
+}
+
+impl std::fmt::Debug for SchemeResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SchemeResponse")
+            .field("status", &self.status)
+            .field("mime_type", &self.mime_type)
+            .field("headers", &self.headers)
+            .finish()
+    }
+}
+
+/// Resolves requests made against a scheme registered through
+/// `AppOptions::register_scheme`, e.g. `app://index.html`, analogous to a
+/// `WebResourceRequestHandler`.
+pub trait SchemeHandler: Send + Sync {
+    fn handle(&self, request: SchemeRequest) -> SchemeResponse;
+}
+
 pub struct App(Arc<wrapper::App>);
 
 impl App {
@@ -159,15 +466,20 @@ impl Drop for App {
 }
 
 pub(crate) mod wrapper {
-    use std::ffi::c_void;
+    use std::{
+        ffi::{c_char, c_int, c_void},
+        sync::Arc,
+    };
 
     #[allow(unused_imports)]
     use webview_sys::{
         close_app, create_app, execute_app, poll_message_loop, quit_message_loop, run_message_loop,
+        HeaderEntry, SchemeHandlerEntry, SchemeResponseRaw,
     };
 
     use crate::{
         ffi, page::wrapper::Page, AppObserver, AppOptions, Args, PageObserver, PageOptions,
+        SchemeHandler, SchemeRequest,
     };
 
     pub struct MessageLoop;
@@ -190,6 +502,10 @@ pub(crate) mod wrapper {
 
     pub(crate) struct App {
         observer: *mut Box<dyn AppObserver>,
+        /// Boxed `Arc<dyn SchemeHandler>` contexts handed to
+        /// `scheme_handle_callback`, kept alive for as long as the app is
+        /// registered with CEF.
+        scheme_handlers: Vec<*mut Arc<dyn SchemeHandler>>,
         pub ptr: *mut c_void,
     }
 
@@ -201,17 +517,88 @@ pub(crate) mod wrapper {
         where
             T: AppObserver + Send + Sync + 'static,
         {
+            // When unset, the helper executable defaults to the main process
+            // binary rather than requiring every embedder to resolve it.
+            let current_exe = std::env::current_exe().ok();
+            let browser_subprocess_path = options
+                .browser_subprocess_path
+                .map(|it| it.to_string())
+                .or_else(|| current_exe.as_ref().and_then(|p| p.to_str()).map(|s| s.to_string()));
+
+            // On macOS, fall back to the bundle-relative default layout that
+            // `wrap_wew` already produces: `<exe>/../../Frameworks/Chromium
+            // Embedded Framework.framework`.
+            #[cfg(target_os = "macos")]
+            let framework_dir_path = options
+                .framework_dir_path
+                .map(|it| it.to_string())
+                .or_else(|| {
+                    current_exe.as_ref().and_then(|p| p.parent()).map(|dir| {
+                        dir.join("../Frameworks/Chromium Embedded Framework.framework")
+                            .to_string_lossy()
+                            .into_owned()
+                    })
+                });
+
+            // Keep the per-switch CStrings alive until after create_app runs.
+            let switch_names = options
+                .command_line_switches
+                .iter()
+                .map(|(name, _)| ffi::into(name))
+                .collect::<Vec<_>>();
+            let switch_values = options
+                .command_line_switches
+                .iter()
+                .map(|(_, value)| ffi::into_opt(*value))
+                .collect::<Vec<_>>();
+            let switches = switch_names
+                .iter()
+                .zip(switch_values.iter())
+                .map(|(&name, &value)| webview_sys::CommandLineSwitch { name, value })
+                .collect::<Vec<_>>();
+
+            // Each handler is boxed separately so its address stays stable
+            // and is handed to `scheme_handle_callback` as `ctx`; the boxes
+            // are freed on `Drop for App`, once CEF can no longer call back
+            // into them.
+            let scheme_names = options
+                .scheme_handlers
+                .iter()
+                .map(|(scheme, _)| ffi::into(scheme))
+                .collect::<Vec<_>>();
+            let scheme_handlers = options
+                .scheme_handlers
+                .iter()
+                .map(|(_, handler)| Box::into_raw(Box::new(handler.clone())))
+                .collect::<Vec<_>>();
+            let scheme_entries = scheme_names
+                .iter()
+                .zip(scheme_handlers.iter())
+                .map(|(&scheme, &ctx)| webview_sys::SchemeHandlerEntry {
+                    scheme,
+                    handle: scheme_handle_callback,
+                    ctx: ctx as _,
+                })
+                .collect::<Vec<_>>();
+
             let mut options = webview_sys::AppOptions {
                 cache_dir_path: ffi::into_opt(options.cache_dir_path),
+                root_cache_path: ffi::into_opt(options.root_cache_path),
                 scheme_dir_path: ffi::into_opt(options.scheme_dir_path),
-                browser_subprocess_path: ffi::into_opt(options.browser_subprocess_path),
+                browser_subprocess_path: ffi::into_opt(browser_subprocess_path.as_deref()),
                 windowless_rendering_enabled: options.windowless_rendering_enabled,
                 external_message_pump: cfg!(target_os = "macos"),
                 multi_threaded_message_loop: !cfg!(target_os = "macos"),
+                command_line_switches: switches.as_ptr(),
+                command_line_switches_len: switches.len(),
+                scheme_handlers: scheme_entries.as_ptr(),
+                scheme_handlers_len: scheme_entries.len(),
+                log_severity: options.log_severity as c_int,
+                log_file: ffi::into_opt(options.log_file),
                 #[cfg(target_os = "macos")]
                 main_bundle_path: ffi::into_opt(options.main_bundle_path),
                 #[cfg(target_os = "macos")]
-                framework_dir_path: ffi::into_opt(options.framework_dir_path),
+                framework_dir_path: ffi::into_opt(framework_dir_path.as_deref()),
                 #[cfg(not(target_os = "macos"))]
                 main_bundle_path: std::ptr::null(),
                 #[cfg(not(target_os = "macos"))]
@@ -225,6 +612,7 @@ pub(crate) mod wrapper {
                     webview_sys::AppObserver {
                         on_context_initialized: Some(on_context_initialized),
                         on_schedule_message_pump_work: Some(on_schedule_message_pump_work),
+                        on_log_message: Some(on_log_message),
                     },
                     observer as _,
                 )
@@ -232,15 +620,33 @@ pub(crate) mod wrapper {
 
             {
                 ffi::free(options.cache_dir_path);
+                ffi::free(options.root_cache_path);
                 ffi::free(options.scheme_dir_path);
                 ffi::free(options.browser_subprocess_path);
+                ffi::free(options.log_file);
+
+                for name in switch_names {
+                    ffi::free(name);
+                }
+                for value in switch_values {
+                    ffi::free(value);
+                }
+                for name in scheme_names {
+                    ffi::free(name);
+                }
             }
 
             if ptr.is_null() {
+                log::error!("webview: create_app failed, CEF context was not initialized");
+
+                for ctx in scheme_handlers {
+                    drop(unsafe { Box::from_raw(ctx) });
+                }
+
                 return None;
             }
 
-            Some(Self { observer, ptr })
+            Some(Self { observer, scheme_handlers, ptr })
         }
 
         pub(crate) fn create_page<T>(
@@ -270,6 +676,10 @@ pub(crate) mod wrapper {
             }
 
             drop(unsafe { Box::from_raw(self.observer) });
+
+            for ctx in self.scheme_handlers.drain(..) {
+                drop(unsafe { Box::from_raw(ctx) });
+            }
         }
     }
 
@@ -278,8 +688,136 @@ pub(crate) mod wrapper {
     }
 
     extern "C" fn on_schedule_message_pump_work(delay: i64, ctx: *mut c_void) {
+        // Flush any batched `post_main_batched` tasks on the existing pump
+        // cadence, in case the queue's own drain task was coalesced away or
+        // delayed.
+        if let Some(queue) = crate::MAIN_THREAD_QUEUE.get() {
+            queue.drain();
+        }
+
         unsafe { &*(ctx as *mut Box<dyn AppObserver>) }.on_schedule_message_pump_work(delay as u64);
     }
+
+    /// Bridges CEF's own log records (browser-process and renderer
+    /// diagnostics) into the `log` crate at the matching level, so
+    /// downstream apps see them through their normal `env_logger`/
+    /// `tracing-subscriber` pipeline instead of CEF's own log file. This
+    /// runs regardless of which `AppObserver` is installed.
+    extern "C" fn on_log_message(severity: c_int, message: *const c_char, _ctx: *mut c_void) {
+        if let (Some(level), Some(message)) =
+            (crate::LogSeverity::from_raw(severity).to_log_level(), ffi::from(message))
+        {
+            log::log!(level, "{}", message);
+        }
+    }
+
+    /// Called by CEF's custom scheme handling when a request against a
+    /// registered scheme (e.g. `app://index.html`) needs resolving.
+    /// `ctx` is the boxed `Arc<dyn SchemeHandler>` registered for this
+    /// scheme in `App::new`.
+    extern "C" fn scheme_handle_callback(
+        url: *const c_char,
+        method: *const c_char,
+        headers: *const HeaderEntry,
+        headers_len: usize,
+        ctx: *mut c_void,
+    ) -> SchemeResponseRaw {
+        let handler = unsafe { &*(ctx as *const Arc<dyn SchemeHandler>) };
+
+        let headers = if headers.is_null() {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(headers, headers_len) }
+                .iter()
+                .filter_map(|it| Some((ffi::from(it.name)?, ffi::from(it.value)?)))
+                .collect()
+        };
+
+        let response = handler.handle(SchemeRequest {
+            url: ffi::from(url).unwrap_or_default(),
+            method: ffi::from(method).unwrap_or_default(),
+            headers,
+        });
+
+        let header_names = response
+            .headers
+            .iter()
+            .map(|(name, _)| ffi::into(name))
+            .collect::<Vec<_>>();
+        let header_values = response
+            .headers
+            .iter()
+            .map(|(_, value)| ffi::into(value))
+            .collect::<Vec<_>>();
+        let headers = header_names
+            .iter()
+            .zip(header_values.iter())
+            .map(|(&name, &value)| HeaderEntry { name, value })
+            .collect::<Vec<_>>();
+        let headers = headers.into_boxed_slice();
+        let headers_ptr = headers.as_ptr();
+        let headers_len = headers.len();
+
+        let mime_type = ffi::into(&response.mime_type);
+
+        SchemeResponseRaw {
+            status: response.status,
+            mime_type,
+            headers: headers_ptr,
+            headers_len,
+            // Transfers ownership of the body reader and every CString
+            // backing `mime_type`/`headers` to CEF; all of it is dropped
+            // in `scheme_response_free` once the glue is done reading the
+            // body and copying the status/headers out.
+            body_ctx: Box::into_raw(Box::new((
+                response.body,
+                mime_type,
+                headers,
+                header_names,
+                header_values,
+            ))) as _,
+            read: scheme_body_read,
+            free: scheme_response_free,
+        }
+    }
+
+    type SchemeBody = (
+        Box<dyn std::io::Read + Send>,
+        *const c_char,
+        Box<[HeaderEntry]>,
+        Vec<*const c_char>,
+        Vec<*const c_char>,
+    );
+
+    /// Pulls the next chunk of a scheme response's body into `buf` (up to
+    /// `len` bytes). Returns the number of bytes written, or a negative
+    /// value on error; zero means end of stream.
+    extern "C" fn scheme_body_read(body_ctx: *mut c_void, buf: *mut c_void, len: usize) -> isize {
+        let (body, ..) = unsafe { &mut *(body_ctx as *mut SchemeBody) };
+        let buf = unsafe { std::slice::from_raw_parts_mut(buf as *mut u8, len) };
+
+        match body.read(buf) {
+            Ok(n) => n as isize,
+            Err(_) => -1,
+        }
+    }
+
+    /// Called once CEF is done streaming a scheme response's body and has
+    /// copied out its status/headers, releasing everything handed back by
+    /// `scheme_handle_callback`.
+    extern "C" fn scheme_response_free(body_ctx: *mut c_void) {
+        let (_, mime_type, _, header_names, header_values) =
+            *unsafe { Box::from_raw(body_ctx as *mut SchemeBody) };
+
+        ffi::free(mime_type);
+
+        for name in header_names {
+            ffi::free(name);
+        }
+        for value in header_values {
+            ffi::free(value);
+        }
+    }
 }
 
 pub mod ffi {
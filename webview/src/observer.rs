@@ -1,5 +1,44 @@
 use webview_sys::{PageState, Rect};
 
+use crate::page::{SharedTextureFormat, SharedTextureHandle};
+
+/// Boolean accessibility states carried by an [`AccessibilityNode`],
+/// mirroring the subset of Chromium's `ax::mojom::State` this crate
+/// surfaces.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AccessibilityState {
+    pub focused: bool,
+    pub focusable: bool,
+    pub checked: bool,
+    pub disabled: bool,
+    pub expanded: bool,
+    pub selected: bool,
+    pub invisible: bool,
+}
+
+/// A node in the accessibility tree delivered to
+/// [`Observer::on_accessibility_tree_change`].
+///
+/// Mirrors the subset of Chromium's `ui::AXNodeData` needed to expose the
+/// page's accessibility tree to assistive technology or automated testing:
+/// the node's role, label, value, on-screen bounds, state flags, and
+/// children in document order.
+#[derive(Debug, Clone)]
+pub struct AccessibilityNode {
+    /// The node's semantic role (e.g. `"button"`, `"heading"`, `"textbox"`).
+    pub role: String,
+    /// The node's accessible name (label).
+    pub name: String,
+    /// The node's accessible value, if any (e.g. an input's current text).
+    pub value: Option<String>,
+    /// The node's bounding rectangle in view coordinates.
+    pub bounds: Rect,
+    /// Boolean accessibility states set on this node.
+    pub state: AccessibilityState,
+    /// Child nodes, in document order.
+    pub children: Vec<AccessibilityNode>,
+}
+
 #[allow(unused)]
 pub trait Observer: Send + Sync {
     /// Implement this interface to handle events related to browser load
@@ -25,6 +64,23 @@ pub trait Observer: Send + Sync {
     /// is only called when CefWindowInfo::shared_texture_enabled is set to
     /// false.
     fn on_frame(&self, texture: &[u8], width: u32, height: u32) {}
+    /// Called instead of `on_frame` when `PageOptions::shared_texture_enabled`
+    /// is set and CEF was able to hand back an already-composited GPU
+    /// surface via `OnAcceleratedPaint` rather than a CPU pixel buffer.
+    /// `handle` is a platform shared-texture handle (IOSurface on macOS,
+    /// D3D11 shared handle on Windows, native pixmap/dmabuf on Linux) that
+    /// can be imported zero-copy by wgpu/Metal/D3D, avoiding a
+    /// `width*height*4` memcpy per frame. CEF falls back to calling
+    /// `on_frame` for frames where acceleration was unavailable, so both
+    /// callbacks must be handled.
+    fn on_accelerated_paint(
+        &self,
+        handle: SharedTextureHandle,
+        format: SharedTextureFormat,
+        width: u32,
+        height: u32,
+    ) {
+    }
     /// Called when the page title changes.
     fn on_title_change(&self, title: String) {}
     /// Called when web content in the page has toggled fullscreen mode.
@@ -39,6 +95,10 @@ pub trait Observer: Send + Sync {
     /// called during the fullscreen transition for notification purposes.
     fn on_fullscreen_change(&self, fullscreen: bool) {}
     fn on_message(&self, message: String) {}
+    /// Called when the page's accessibility tree changes, if accessibility
+    /// was enabled for this page. `tree` is the full tree rooted at the
+    /// page's root node.
+    fn on_accessibility_tree_change(&self, tree: AccessibilityNode) {}
 }
 
 pub(crate) mod wrapper {
@@ -51,14 +111,18 @@ pub(crate) mod wrapper {
 
     use crate::ffi;
 
+    use super::{AccessibilityNode, AccessibilityState};
+
     pub fn create_page_observer() -> PageObserver {
         PageObserver {
             on_state_change: Some(on_state_change_callback),
             on_ime_rect: Some(on_ime_rect_callback),
             on_frame: Some(on_frame_callback),
+            on_accelerated_paint: Some(on_accelerated_paint_callback),
             on_title_change: Some(on_title_change_callback),
             on_fullscreen_change: Some(on_fullscreen_change_callback),
             on_message: Some(on_message_callback),
+            on_accessibility_tree_change: Some(on_accessibility_tree_change_callback),
         }
     }
 
@@ -103,6 +167,25 @@ pub(crate) mod wrapper {
         );
     }
 
+    /// Called instead of `on_frame_callback` when CEF delivered an
+    /// already-composited GPU surface via `OnAcceleratedPaint`.
+    extern "C" fn on_accelerated_paint_callback(
+        handle: *mut c_void,
+        format: c_int,
+        width: c_int,
+        height: c_int,
+        ctx: *mut c_void,
+    ) {
+        let handle = super::SharedTextureHandle::from_raw(handle);
+        let format = match format {
+            0 => super::SharedTextureFormat::Bgra8,
+            _ => super::SharedTextureFormat::Nv12,
+        };
+
+        (unsafe { &*(ctx as *mut Box<dyn super::Observer>) })
+            .on_accelerated_paint(handle, format, width as u32, height as u32);
+    }
+
     /// Called when the page title changes.
     extern "C" fn on_title_change_callback(title: *const c_char, ctx: *mut c_void) {
         if let Some(title) = ffi::from(title) {
@@ -131,4 +214,62 @@ pub(crate) mod wrapper {
             (unsafe { &*(ctx as *mut Box<dyn super::Observer>) }).on_message(message);
         }
     }
+
+    /// Bitflags packed into `webview_sys::AccessibilityNode::state`, in the
+    /// same order as `AccessibilityState`'s fields.
+    const AX_STATE_FOCUSED: u32 = 1 << 0;
+    const AX_STATE_FOCUSABLE: u32 = 1 << 1;
+    const AX_STATE_CHECKED: u32 = 1 << 2;
+    const AX_STATE_DISABLED: u32 = 1 << 3;
+    const AX_STATE_EXPANDED: u32 = 1 << 4;
+    const AX_STATE_SELECTED: u32 = 1 << 5;
+    const AX_STATE_INVISIBLE: u32 = 1 << 6;
+
+    /// Called when the page's accessibility tree changes. `tree` points at
+    /// the root node of the full tree; it and everything reachable from it
+    /// are only valid for the duration of this call.
+    extern "C" fn on_accessibility_tree_change_callback(
+        tree: *const webview_sys::AccessibilityNode,
+        ctx: *mut c_void,
+    ) {
+        if tree.is_null() {
+            return;
+        }
+
+        let tree = unsafe { convert_accessibility_node(&*tree) };
+
+        (unsafe { &*(ctx as *mut Box<dyn super::Observer>) }).on_accessibility_tree_change(tree);
+    }
+
+    /// Recursively convert a raw `webview_sys::AccessibilityNode` tree into
+    /// an owned [`AccessibilityNode`].
+    fn convert_accessibility_node(raw: &webview_sys::AccessibilityNode) -> AccessibilityNode {
+        let state = raw.state;
+
+        let children = if raw.children.is_null() {
+            Vec::new()
+        } else {
+            unsafe { from_raw_parts(raw.children, raw.children_len) }
+                .iter()
+                .map(convert_accessibility_node)
+                .collect()
+        };
+
+        AccessibilityNode {
+            role: ffi::from(raw.role).unwrap_or_default(),
+            name: ffi::from(raw.name).unwrap_or_default(),
+            value: ffi::from(raw.value),
+            bounds: raw.bounds,
+            state: AccessibilityState {
+                focused: state & AX_STATE_FOCUSED != 0,
+                focusable: state & AX_STATE_FOCUSABLE != 0,
+                checked: state & AX_STATE_CHECKED != 0,
+                disabled: state & AX_STATE_DISABLED != 0,
+                expanded: state & AX_STATE_EXPANDED != 0,
+                selected: state & AX_STATE_SELECTED != 0,
+                invisible: state & AX_STATE_INVISIBLE != 0,
+            },
+            children,
+        }
+    }
 }